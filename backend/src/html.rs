@@ -1,6 +1,7 @@
 //! HTML Templates
 /// Production HTML template
 pub const HTML_TEMPLATE: &str = r#"
+<title>${title}</title>
 <script type="module">
  import init, { ${run} } from '${entry}';
  (async () => {
@@ -12,6 +13,7 @@ pub const HTML_TEMPLATE: &str = r#"
 
 /// Development HTML template
 pub const DEV_HTML_TEMPLATE: &str = r#"
+<title>${title}</title>
 <script type="module">
  import init, { ${run} } from '${entry}';
  (async () => {
@@ -30,3 +32,12 @@ pub const DEV_HTML_TEMPLATE: &str = r#"
  })();
 </script>
 "#;
+
+/// Escape text in html
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
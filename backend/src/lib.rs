@@ -7,9 +7,10 @@ mod client;
 mod err;
 mod html;
 mod manifest;
+mod pages;
 mod server;
 
 #[macro_use]
 extern crate log;
 
-pub use self::{err::Error, manifest::Crate};
+pub use self::{err::Error, manifest::Crate, pages::Page};
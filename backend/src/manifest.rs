@@ -2,19 +2,19 @@
 use crate::{
     cargo::{CargoManifest, ManifestAndUnsedKeys},
     err::Error,
-    html::{DEV_HTML_TEMPLATE, HTML_TEMPLATE},
+    html::{self, DEV_HTML_TEMPLATE, HTML_TEMPLATE},
     logger::Logger,
+    pages::{self, Page},
     server,
 };
 use cargo_metadata::{Metadata, MetadataCommand};
-use etc::{Etc, FileSystem};
 use notify::{DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
 use std::fs::File;
 use std::io::prelude::*;
 use std::{
     collections::BTreeSet,
     env, fs,
-    path::PathBuf,
+    path::{Path, PathBuf},
     process::{Command, ExitStatus},
     sync::mpsc::{channel, Sender},
     time::Duration,
@@ -193,6 +193,7 @@ impl Crate {
                                     }
                                 }
 
+                                self.write_pages(DEV_HTML_TEMPLATE)?;
                                 self.compile_and_bindgen()?;
                                 wtx.send(true).unwrap_or_default();
                             }
@@ -208,6 +209,7 @@ impl Crate {
     /// Compile APP
     pub fn build(&self) -> Result<(), Error> {
         self.write_pages(HTML_TEMPLATE)?;
+        self.write_assets()?;
         self.compile()?;
         self.bindgen()
     }
@@ -215,6 +217,7 @@ impl Crate {
     /// Serve APP
     pub fn serve(self, port: u16) -> Result<(), Error> {
         self.write_pages(DEV_HTML_TEMPLATE)?;
+        self.write_assets()?;
         if let Err(e) = server::run(self, port) {
             logger!(Logger::ServerStartFailed, e);
             return Err(Error::Custom(format!("{:?}", e)));
@@ -224,24 +227,63 @@ impl Crate {
     }
 
     fn write_pages(&self, template: &str) -> Result<(), Error> {
-        let codegen = |p: &str| {
+        for page in self.pages()? {
+            let file = self.wasm.join(page.html());
+            if let Some(dir) = file.parent() {
+                fs::create_dir_all(dir)?;
+            }
+
             fs::write(
-                &self.wasm.join(format!("{}.html", &p)),
+                file,
                 template
                     .replace("${entry}", &["/", &self.name(), ".js"].join(""))
-                    .replace("${run}", &p),
-            )
-        };
-
-        let pages = self.root.join("pages");
-        if pages.exists() {
-            for f in Etc::new(&self.root.join("pages"))?.ls()? {
-                if f.ends_with(".rs") && f != "mod.rs" {
-                    codegen(&f[..f.len() - 3])?;
+                    .replace("${title}", &html::escape(&page.title))
+                    .replace("${run}", &page.name),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Pages declared with `#[page]` in the lib target, the `index` page if
+    /// there are none
+    pub fn pages(&self) -> Result<Vec<Page>, Error> {
+        let pkg = &self.data.packages[self.idx];
+        let lib = pkg
+            .targets
+            .iter()
+            .find(|t| t.kind.iter().any(|k| k == "lib" || k == "cdylib"))
+            .ok_or_else(|| Error::Custom("Failed to find lib target".to_string()))?;
+
+        let pages = pages::collect(&lib.src_path)?;
+        if pages.is_empty() {
+            return Ok(vec![Page::index(&self.name())]);
+        }
+
+        Ok(pages)
+    }
+
+    /// Copy font files under `assets` to the out dir, keeping their paths
+    fn write_assets(&self) -> Result<(), Error> {
+        let assets = self.root.join("assets");
+        if assets.exists() {
+            Crate::copy_fonts(&assets, &self.wasm.join("assets"))?;
+        }
+
+        Ok(())
+    }
+
+    fn copy_fonts(from: &Path, to: &Path) -> Result<(), Error> {
+        for entry in fs::read_dir(from)? {
+            let path = entry?.path();
+            if let Some(name) = path.file_name() {
+                if path.is_dir() {
+                    Crate::copy_fonts(&path, &to.join(name))?;
+                } else if path.extension().map_or(false, |ext| ext == "woff2") {
+                    fs::create_dir_all(to)?;
+                    fs::copy(&path, to.join(name))?;
                 }
             }
-        } else {
-            codegen("index")?;
         }
 
         Ok(())
@@ -0,0 +1,155 @@
+//! Page metadata
+use crate::err::Error;
+use std::{fs, path::Path};
+use syn::{Attribute, Item, Lit, Meta, NestedMeta};
+
+/// Metadata of `#[page]`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Page {
+    /// Exported function name
+    pub name: String,
+    /// Page route, `/users/:id`
+    pub route: String,
+    /// Document title
+    pub title: String,
+}
+
+impl Page {
+    /// The `index` page at `/`
+    pub fn index(title: &str) -> Page {
+        Page {
+            name: "index".into(),
+            route: "/".into(),
+            title: title.into(),
+        }
+    }
+
+    /// Parse page from the attributes of item
+    fn from_attrs(ident: &str, attrs: &[Attribute]) -> Result<Option<Page>, Error> {
+        let attr = match attrs.iter().find(|a| {
+            a.path
+                .segments
+                .last()
+                .map(|s| s.ident == "page")
+                .unwrap_or(false)
+        }) {
+            Some(attr) => attr,
+            None => return Ok(None),
+        };
+
+        let mut page = Page {
+            name: ident.to_lowercase(),
+            route: "".into(),
+            title: ident.into(),
+        };
+
+        if let Meta::List(list) = attr
+            .parse_meta()
+            .map_err(|e| Error::Custom(e.to_string()))?
+        {
+            for arg in list.nested {
+                if let NestedMeta::Meta(Meta::NameValue(nv)) = arg {
+                    if let Lit::Str(s) = nv.lit {
+                        if nv.path.is_ident("name") {
+                            page.name = s.value();
+                        } else if nv.path.is_ident("route") {
+                            page.route = s.value();
+                        } else if nv.path.is_ident("title") {
+                            page.title = s.value();
+                        }
+                    }
+                }
+            }
+        }
+
+        if page.route.is_empty() {
+            page.route = match page.name.as_str() {
+                "index" => "/".into(),
+                name => format!("/{}", name),
+            };
+        }
+
+        Ok(Some(page))
+    }
+
+    /// The html file of page, the static prefix of route
+    pub fn html(&self) -> String {
+        let path = self
+            .route
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .take_while(|s| !s.starts_with(':') && !s.starts_with('*'))
+            .collect::<Vec<&str>>()
+            .join("/");
+
+        if path.is_empty() {
+            "index.html".into()
+        } else {
+            format!("{}.html", path)
+        }
+    }
+
+    /// If the request path matches route
+    pub fn matches(&self, path: &str) -> bool {
+        let path = path.trim_end_matches(".html");
+        let route = self.route.split('/').filter(|s| !s.is_empty());
+        let mut path = path.split('/').filter(|s| !s.is_empty());
+        for seg in route {
+            if seg.starts_with('*') {
+                return true;
+            }
+
+            match path.next() {
+                Some(p) if seg.starts_with(':') || p == seg => {}
+                _ => return false,
+            }
+        }
+
+        path.next().is_none()
+    }
+}
+
+/// Collect pages in the module tree of `lib`
+pub fn collect(lib: &Path) -> Result<Vec<Page>, Error> {
+    let mut pages = vec![];
+    let dir = lib.parent().unwrap_or_else(|| Path::new("."));
+    walk_file(lib, dir, &mut pages)?;
+
+    Ok(pages)
+}
+
+fn walk_file(file: &Path, dir: &Path, pages: &mut Vec<Page>) -> Result<(), Error> {
+    let src = fs::read_to_string(file)?;
+    let ast = syn::parse_file(&src)
+        .map_err(|e| Error::Custom(format!("parse {} failed: {}", file.to_string_lossy(), e)))?;
+
+    walk_items(&ast.items, dir, pages)
+}
+
+fn walk_items(items: &[Item], dir: &Path, pages: &mut Vec<Page>) -> Result<(), Error> {
+    for item in items {
+        let page = match item {
+            Item::Struct(s) => Page::from_attrs(&s.ident.to_string(), &s.attrs)?,
+            Item::Enum(e) => Page::from_attrs(&e.ident.to_string(), &e.attrs)?,
+            Item::Mod(m) => {
+                let sub = dir.join(m.ident.to_string());
+                if let Some((_, items)) = &m.content {
+                    walk_items(items, &sub, pages)?;
+                } else if sub.with_extension("rs").exists() {
+                    walk_file(&sub.with_extension("rs"), &sub, pages)?;
+                } else if sub.join("mod.rs").exists() {
+                    walk_file(&sub.join("mod.rs"), &sub, pages)?;
+                }
+
+                None
+            }
+            _ => None,
+        };
+
+        if let Some(page) = page {
+            pages.push(page);
+        }
+    }
+
+    Ok(())
+}
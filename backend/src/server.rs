@@ -1,7 +1,10 @@
 use crate::{client, err::Error, logger::Logger, manifest::Crate};
 use futures::join;
-use std::sync::{mpsc::channel, Arc, Mutex};
-use warp::{ws::Ws, Filter};
+use std::{
+    fs,
+    sync::{mpsc::channel, Arc, Mutex},
+};
+use warp::{path::FullPath, ws::Ws, Filter, Rejection};
 
 /// Serve the backend
 #[tokio::main]
@@ -11,6 +14,25 @@ pub async fn run(mani: Crate, port: u16) -> Result<(), Error> {
     let rx = warp::any().map(move || rx.clone());
 
     let index = warp::filters::fs::dir(mani.wasm().clone());
+
+    // serve pages with dynamic routes, `/users/:id`
+    let pages = Arc::new(mani.pages()?);
+    let out = mani.wasm().clone();
+    let routes = warp::get()
+        .and(warp::path::full())
+        .and_then(move |path: FullPath| {
+            let html = pages
+                .iter()
+                .find(|p| p.matches(path.as_str()))
+                .and_then(|p| fs::read_to_string(out.join(p.html())).ok());
+
+            async move {
+                match html {
+                    Some(html) => Ok(warp::reply::html(html)),
+                    None => Err::<_, Rejection>(warp::reject::not_found()),
+                }
+            }
+        });
     let updater = warp::path("updater")
         .and(warp::ws())
         .and(rx)
@@ -18,7 +40,7 @@ pub async fn run(mani: Crate, port: u16) -> Result<(), Error> {
 
     // dev http server
     logger!(Logger::ServerStart, port);
-    let server = warp::serve(index.or(updater)).run(([0, 0, 0, 0], port));
+    let server = warp::serve(index.or(updater).or(routes)).run(([0, 0, 0, 0], port));
 
     // file watcher
     let watcher = tokio::task::spawn_blocking(move || mani.watch(tx));
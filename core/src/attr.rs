@@ -1,4 +1,36 @@
 use elvis_core_support::Setter;
+use std::collections::BTreeMap;
+
+/// Element namespaces
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Namespace {
+    /// Html elements
+    Html,
+    /// Svg elements
+    Svg,
+    /// MathML elements
+    MathMl,
+}
+
+impl Namespace {
+    /// Namespace uri
+    pub fn uri(&self) -> &'static str {
+        match self {
+            Namespace::Html => "http://www.w3.org/1999/xhtml",
+            Namespace::Svg => "http://www.w3.org/2000/svg",
+            Namespace::MathMl => "http://www.w3.org/1998/Math/MathML",
+        }
+    }
+
+    /// Namespace of root tags, `svg` and `math`
+    pub fn of_tag(tag: &str) -> Option<Namespace> {
+        match tag {
+            "svg" => Some(Namespace::Svg),
+            "math" => Some(Namespace::MathMl),
+            _ => None,
+        }
+    }
+}
 
 /// Node Attributes
 #[derive(Clone, Default, PartialEq, PartialOrd, Eq, Ord, Setter)]
@@ -15,4 +47,32 @@ pub struct Attribute {
     pub text: String,
     /// Type
     pub r#type: String,
+    /// Element namespace, inherits from parent if none
+    pub namespace: Option<Namespace>,
+    /// Other html attributes, like `name`, `value` or `disabled`
+    #[skip]
+    pub props: BTreeMap<String, String>,
+}
+
+impl Attribute {
+    /// Set html attribute
+    pub fn prop(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.props.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set `data-key`, scroll views scroll to it by key
+    pub fn key(self, key: &str) -> Self {
+        self.prop("data-key", key)
+    }
+
+    /// Set boolean html attribute, removed if false
+    pub fn flag(mut self, key: &str, on: bool) -> Self {
+        if on {
+            self.props.insert(key.into(), "".into());
+        } else {
+            self.props.remove(key);
+        }
+        self
+    }
 }
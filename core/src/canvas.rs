@@ -0,0 +1,831 @@
+//! Canvas drawing
+//!
+//! A `Drawing` retains draw commands, platforms replay them on their 2D
+//! contexts, and `Recorder` records them as calls in native tests.
+use crate::{value::Color, Error};
+use std::{f64::consts::PI, fmt, str::FromStr};
+
+/// 2D affine transform, `[a, b, c, d, e, f]` of canvas
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform(pub [f64; 6]);
+
+impl Default for Transform {
+    fn default() -> Transform {
+        Transform([1.0, 0.0, 0.0, 1.0, 0.0, 0.0])
+    }
+}
+
+impl Transform {
+    /// Translate transform
+    pub fn translate(x: f64, y: f64) -> Transform {
+        Transform([1.0, 0.0, 0.0, 1.0, x, y])
+    }
+
+    /// Scale transform
+    pub fn scale(x: f64, y: f64) -> Transform {
+        Transform([x, 0.0, 0.0, y, 0.0, 0.0])
+    }
+
+    /// Rotate transform, in radians
+    pub fn rotate(angle: f64) -> Transform {
+        let (sin, cos) = angle.sin_cos();
+        Transform([cos, sin, -sin, cos, 0.0, 0.0])
+    }
+
+    /// Apply `t` after self, the same as calling `transform(t)` in canvas
+    pub fn then(&self, t: &Transform) -> Transform {
+        let [a, b, c, d, e, f] = self.0;
+        let [ta, tb, tc, td, te, tf] = t.0;
+        Transform([
+            a * ta + c * tb,
+            b * ta + d * tb,
+            a * tc + c * td,
+            b * tc + d * td,
+            a * te + c * tf + e,
+            b * te + d * tf + f,
+        ])
+    }
+
+    /// Transform point
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        let [a, b, c, d, e, f] = self.0;
+        (a * x + c * y + e, b * x + d * y + f)
+    }
+
+    /// Inverse transform, none if not invertible
+    pub fn invert(&self) -> Option<Transform> {
+        let [a, b, c, d, e, f] = self.0;
+        let det = a * d - b * c;
+        if det.abs() < f64::EPSILON {
+            return None;
+        }
+
+        Some(Transform([
+            d / det,
+            -b / det,
+            -c / det,
+            a / det,
+            (c * f - d * e) / det,
+            (b * e - a * f) / det,
+        ]))
+    }
+}
+
+/// Path operation
+#[derive(Clone, Debug, PartialEq)]
+pub enum PathOp {
+    /// Start a sub path at `(x, y)`
+    MoveTo(f64, f64),
+    /// Line to `(x, y)`
+    LineTo(f64, f64),
+    /// Quadratic curve with control point to `(x, y)`
+    QuadTo(f64, f64, f64, f64),
+    /// Cubic curve with two control points to `(x, y)`
+    CubicTo(f64, f64, f64, f64, f64, f64),
+    /// Arc with center, radius, start and end angles, anticlockwise
+    Arc(f64, f64, f64, f64, f64, bool),
+    /// Rectangle sub path with `(x, y)`, width and height
+    Rect(f64, f64, f64, f64),
+    /// Close sub path
+    Close,
+}
+
+/// Canvas path
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path(pub Vec<PathOp>);
+
+impl Path {
+    /// New path
+    pub fn new() -> Path {
+        Path::default()
+    }
+
+    /// Move to `(x, y)`
+    pub fn move_to(mut self, x: f64, y: f64) -> Path {
+        self.0.push(PathOp::MoveTo(x, y));
+        self
+    }
+
+    /// Line to `(x, y)`
+    pub fn line_to(mut self, x: f64, y: f64) -> Path {
+        self.0.push(PathOp::LineTo(x, y));
+        self
+    }
+
+    /// Quadratic curve to `(x, y)`
+    pub fn quad_to(mut self, cx: f64, cy: f64, x: f64, y: f64) -> Path {
+        self.0.push(PathOp::QuadTo(cx, cy, x, y));
+        self
+    }
+
+    /// Cubic curve to `(x, y)`
+    pub fn cubic_to(mut self, c1x: f64, c1y: f64, c2x: f64, c2y: f64, x: f64, y: f64) -> Path {
+        self.0.push(PathOp::CubicTo(c1x, c1y, c2x, c2y, x, y));
+        self
+    }
+
+    /// Clockwise arc
+    pub fn arc(mut self, x: f64, y: f64, radius: f64, start: f64, end: f64) -> Path {
+        self.0.push(PathOp::Arc(x, y, radius, start, end, false));
+        self
+    }
+
+    /// Anticlockwise arc
+    pub fn arc_ccw(mut self, x: f64, y: f64, radius: f64, start: f64, end: f64) -> Path {
+        self.0.push(PathOp::Arc(x, y, radius, start, end, true));
+        self
+    }
+
+    /// Rectangle
+    pub fn rect(mut self, x: f64, y: f64, width: f64, height: f64) -> Path {
+        self.0.push(PathOp::Rect(x, y, width, height));
+        self
+    }
+
+    /// Circle
+    pub fn circle(self, x: f64, y: f64, radius: f64) -> Path {
+        self.move_to(x + radius, y).arc(x, y, radius, 0.0, 2.0 * PI)
+    }
+
+    /// Close sub path
+    pub fn close(mut self) -> Path {
+        self.0.push(PathOp::Close);
+        self
+    }
+
+    /// Flatten into polygons of sub paths
+    pub fn polygons(&self) -> Vec<Vec<(f64, f64)>> {
+        let mut polygons: Vec<Vec<(f64, f64)>> = vec![];
+        let mut current: Vec<(f64, f64)> = vec![];
+        let end = |polygons: &mut Vec<_>, current: &mut Vec<_>| {
+            if !current.is_empty() {
+                polygons.push(std::mem::take(current));
+            }
+        };
+
+        for op in self.0.iter() {
+            let last = current.last().copied().unwrap_or((0.0, 0.0));
+            match *op {
+                PathOp::MoveTo(x, y) => {
+                    end(&mut polygons, &mut current);
+                    current.push((x, y));
+                }
+                PathOp::LineTo(x, y) => current.push((x, y)),
+                PathOp::QuadTo(cx, cy, x, y) => {
+                    for i in 1..=16 {
+                        let t = i as f64 / 16.0;
+                        let u = 1.0 - t;
+                        current.push((
+                            u * u * last.0 + 2.0 * u * t * cx + t * t * x,
+                            u * u * last.1 + 2.0 * u * t * cy + t * t * y,
+                        ));
+                    }
+                }
+                PathOp::CubicTo(c1x, c1y, c2x, c2y, x, y) => {
+                    for i in 1..=16 {
+                        let t = i as f64 / 16.0;
+                        let u = 1.0 - t;
+                        current.push((
+                            u * u * u * last.0
+                                + 3.0 * u * u * t * c1x
+                                + 3.0 * u * t * t * c2x
+                                + t * t * t * x,
+                            u * u * u * last.1
+                                + 3.0 * u * u * t * c1y
+                                + 3.0 * u * t * t * c2y
+                                + t * t * t * y,
+                        ));
+                    }
+                }
+                PathOp::Arc(x, y, r, from, to, ccw) => {
+                    let sweep = sweep(from, to, ccw);
+                    let steps = ((sweep.abs() / (PI / 16.0)).ceil() as usize).max(1);
+                    for i in 0..=steps {
+                        let a = from + sweep * i as f64 / steps as f64;
+                        current.push((x + r * a.cos(), y + r * a.sin()));
+                    }
+                }
+                PathOp::Rect(x, y, w, h) => {
+                    end(&mut polygons, &mut current);
+                    polygons.push(vec![(x, y), (x + w, y), (x + w, y + h), (x, y + h)]);
+                    current.push((x, y));
+                }
+                PathOp::Close => {
+                    let first = current.first().copied();
+                    end(&mut polygons, &mut current);
+                    if let Some(first) = first {
+                        current.push(first);
+                    }
+                }
+            }
+        }
+
+        end(&mut polygons, &mut current);
+        polygons.retain(|p| p.len() > 2);
+        polygons
+    }
+
+    /// If point is in path, with the nonzero rule
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let mut winding = 0;
+        for polygon in self.polygons() {
+            for (i, &(x0, y0)) in polygon.iter().enumerate() {
+                let (x1, y1) = polygon[(i + 1) % polygon.len()];
+                let side = (x1 - x0) * (y - y0) - (x - x0) * (y1 - y0);
+                if y0 <= y && y1 > y && side > 0.0 {
+                    winding += 1;
+                } else if y0 > y && y1 <= y && side < 0.0 {
+                    winding -= 1;
+                }
+            }
+        }
+
+        winding != 0
+    }
+}
+
+/// Sweep angle of arc, the same as canvas
+fn sweep(start: f64, end: f64, ccw: bool) -> f64 {
+    let full = 2.0 * PI;
+    if !ccw && end - start >= full {
+        full
+    } else if ccw && start - end >= full {
+        -full
+    } else if !ccw {
+        (end - start).rem_euclid(full)
+    } else {
+        -(start - end).rem_euclid(full)
+    }
+}
+
+/// Paint of fills, strokes and texts
+#[derive(Clone, Debug, PartialEq)]
+pub enum Paint {
+    /// Solid color
+    Color(Color),
+    /// Linear gradient from `(x0, y0)` to `(x1, y1)` with color stops
+    Linear(f64, f64, f64, f64, Vec<(f64, Color)>),
+    /// Radial gradient from circle `(x0, y0, r0)` to `(x1, y1, r1)` with
+    /// color stops
+    Radial(f64, f64, f64, f64, f64, f64, Vec<(f64, Color)>),
+}
+
+impl Default for Paint {
+    fn default() -> Paint {
+        Paint::Color(Color::Black)
+    }
+}
+
+impl From<Color> for Paint {
+    fn from(color: Color) -> Paint {
+        Paint::Color(color)
+    }
+}
+
+/// Draw command
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    /// Save transform
+    Save,
+    /// Restore transform
+    Restore,
+    /// Multiply transform
+    Transform(Transform),
+    /// Fill path
+    Fill(Path, Paint),
+    /// Stroke path with line width
+    Stroke(Path, Paint, f64),
+    /// Fill text at `(x, y)` with css font
+    Text(String, f64, f64, String, Paint),
+    /// Hit region with key, not painted
+    Region(String, Path),
+}
+
+/// 2D context of platforms
+pub trait Context2d {
+    /// Save state
+    fn save(&mut self);
+    /// Restore state
+    fn restore(&mut self);
+    /// Multiply transform
+    fn transform(&mut self, t: &Transform);
+    /// Begin a new path
+    fn path(&mut self, path: &Path);
+    /// Fill the current path
+    fn fill(&mut self, paint: &Paint);
+    /// Stroke the current path
+    fn stroke(&mut self, paint: &Paint, width: f64);
+    /// Fill text
+    fn text(&mut self, text: &str, x: f64, y: f64, font: &str, paint: &Paint);
+}
+
+/// Retained drawing
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Drawing(pub Vec<Command>);
+
+impl Drawing {
+    /// New drawing
+    pub fn new() -> Drawing {
+        Drawing::default()
+    }
+
+    /// Save transform
+    pub fn save(mut self) -> Drawing {
+        self.0.push(Command::Save);
+        self
+    }
+
+    /// Restore transform
+    pub fn restore(mut self) -> Drawing {
+        self.0.push(Command::Restore);
+        self
+    }
+
+    /// Multiply transform
+    pub fn transform(mut self, t: Transform) -> Drawing {
+        self.0.push(Command::Transform(t));
+        self
+    }
+
+    /// Fill path
+    pub fn fill(mut self, path: Path, paint: impl Into<Paint>) -> Drawing {
+        self.0.push(Command::Fill(path, paint.into()));
+        self
+    }
+
+    /// Stroke path
+    pub fn stroke(mut self, path: Path, paint: impl Into<Paint>, width: f64) -> Drawing {
+        self.0.push(Command::Stroke(path, paint.into(), width));
+        self
+    }
+
+    /// Fill rectangle
+    pub fn fill_rect(self, x: f64, y: f64, w: f64, h: f64, paint: impl Into<Paint>) -> Drawing {
+        self.fill(Path::new().rect(x, y, w, h), paint)
+    }
+
+    /// Fill text
+    pub fn text(
+        mut self,
+        text: &str,
+        x: f64,
+        y: f64,
+        font: &str,
+        paint: impl Into<Paint>,
+    ) -> Drawing {
+        self.0
+            .push(Command::Text(text.into(), x, y, font.into(), paint.into()));
+        self
+    }
+
+    /// Add hit region with key
+    pub fn region(mut self, key: &str, path: Path) -> Drawing {
+        self.0.push(Command::Region(key.into(), path));
+        self
+    }
+
+    /// Replay on context
+    pub fn draw(&self, ctx: &mut impl Context2d) {
+        for cmd in self.0.iter() {
+            match cmd {
+                Command::Save => ctx.save(),
+                Command::Restore => ctx.restore(),
+                Command::Transform(t) => ctx.transform(t),
+                Command::Fill(path, paint) => {
+                    ctx.path(path);
+                    ctx.fill(paint);
+                }
+                Command::Stroke(path, paint, width) => {
+                    ctx.path(path);
+                    ctx.stroke(paint, *width);
+                }
+                Command::Text(text, x, y, font, paint) => ctx.text(text, *x, *y, font, paint),
+                Command::Region(..) => {}
+            }
+        }
+    }
+
+    /// Key of the top region at `(x, y)`
+    pub fn hit(&self, x: f64, y: f64) -> Option<String> {
+        let mut stack = vec![];
+        let mut current = Transform::default();
+        let mut res = None;
+        for cmd in self.0.iter() {
+            match cmd {
+                Command::Save => stack.push(current),
+                Command::Restore => current = stack.pop().unwrap_or_default(),
+                Command::Transform(t) => current = current.then(t),
+                Command::Region(key, path) => {
+                    if let Some(inv) = current.invert() {
+                        let (px, py) = inv.apply(x, y);
+                        if path.contains(px, py) {
+                            res = Some(key.clone());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        res
+    }
+}
+
+/// Percent-encode whitespaces, `%` and `;` in string, tokens split at any
+/// whitespace
+fn escape(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_whitespace() || c == '%' || c == ';' {
+            let mut buf = [0; 4];
+            for b in c.encode_utf8(&mut buf).bytes() {
+                res.push_str(&format!("%{:02X}", b));
+            }
+        } else {
+            res.push(c);
+        }
+    }
+
+    res
+}
+
+/// Decode the percent-encoded bytes of string
+fn unescape(s: &str) -> String {
+    let (bytes, mut res) = (s.as_bytes(), vec![]);
+    let mut i = 0;
+    while i < bytes.len() {
+        let code = s
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match code {
+            Some(b) => {
+                res.push(b);
+                i += 3;
+            }
+            None => {
+                res.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&res).into_owned()
+}
+
+fn paint_to_string(paint: &Paint) -> String {
+    let color = |c: &Color| c.to_string().replace(' ', "");
+    let stops = |stops: &[(f64, Color)]| {
+        stops
+            .iter()
+            .map(|(s, c)| format!(";{};{}", s, color(c)))
+            .collect::<String>()
+    };
+
+    match paint {
+        Paint::Color(c) => color(c),
+        Paint::Linear(x0, y0, x1, y1, s) => {
+            format!("linear;{};{};{};{}{}", x0, y0, x1, y1, stops(s))
+        }
+        Paint::Radial(x0, y0, r0, x1, y1, r1, s) => format!(
+            "radial;{};{};{};{};{};{}{}",
+            x0,
+            y0,
+            r0,
+            x1,
+            y1,
+            r1,
+            stops(s)
+        ),
+    }
+}
+
+fn path_to_string(path: &Path) -> String {
+    path.0
+        .iter()
+        .map(|op| match op {
+            PathOp::MoveTo(x, y) => format!(" M {} {}", x, y),
+            PathOp::LineTo(x, y) => format!(" L {} {}", x, y),
+            PathOp::QuadTo(cx, cy, x, y) => format!(" Q {} {} {} {}", cx, cy, x, y),
+            PathOp::CubicTo(a, b, c, d, x, y) => {
+                format!(" C {} {} {} {} {} {}", a, b, c, d, x, y)
+            }
+            PathOp::Arc(x, y, r, s, e, ccw) => {
+                format!(" A {} {} {} {} {} {}", x, y, r, s, e, *ccw as u8)
+            }
+            PathOp::Rect(x, y, w, h) => format!(" R {} {} {} {}", x, y, w, h),
+            PathOp::Close => " Z".into(),
+        })
+        .collect()
+}
+
+/// One command per line, platforms parse it back
+impl fmt::Display for Drawing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let lines = self
+            .0
+            .iter()
+            .map(|cmd| match cmd {
+                Command::Save => "save".to_string(),
+                Command::Restore => "restore".to_string(),
+                Command::Transform(Transform([a, b, c, d, e, f])) => {
+                    format!("transform {} {} {} {} {} {}", a, b, c, d, e, f)
+                }
+                Command::Fill(path, paint) => {
+                    format!("fill {}{}", paint_to_string(paint), path_to_string(path))
+                }
+                Command::Stroke(path, paint, width) => format!(
+                    "stroke {} {}{}",
+                    paint_to_string(paint),
+                    width,
+                    path_to_string(path)
+                ),
+                Command::Text(text, x, y, font, paint) => format!(
+                    "text {} {} {} {} {}",
+                    paint_to_string(paint),
+                    x,
+                    y,
+                    escape(font),
+                    escape(text)
+                ),
+                Command::Region(key, path) => {
+                    format!("region {}{}", escape(key), path_to_string(path))
+                }
+            })
+            .collect::<Vec<String>>();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// Tokens of a command line
+struct Tokens<'t>(std::str::SplitWhitespace<'t>, &'t str);
+
+impl<'t> Tokens<'t> {
+    fn err(&self) -> Error {
+        Error::ParseError(format!("invalid draw command `{}`", self.1))
+    }
+
+    fn str(&mut self) -> Result<&'t str, Error> {
+        let err = self.err();
+        self.0.next().ok_or(err)
+    }
+
+    fn num(&mut self) -> Result<f64, Error> {
+        let err = self.err();
+        self.str()?.parse().map_err(|_| err)
+    }
+
+    /// Color, or gradient of `kind;numbers;offset;color...`
+    fn paint(&mut self) -> Result<Paint, Error> {
+        let token = self.str()?;
+        let mut parts = token.split(';');
+        let kind = parts.next().unwrap_or_default();
+        let len = match kind {
+            "linear" => 4,
+            "radial" => 6,
+            _ => return Ok(Paint::Color(Color::from_str(kind)?)),
+        };
+
+        let mut nums = vec![];
+        for _ in 0..len {
+            nums.push(self.part(parts.next())?);
+        }
+        let mut stops = vec![];
+        while let Some(offset) = parts.next() {
+            let offset = self.part(Some(offset))?;
+            let color = Color::from_str(parts.next().ok_or_else(|| self.err())?)?;
+            stops.push((offset, color));
+        }
+
+        Ok(match kind {
+            "linear" => Paint::Linear(nums[0], nums[1], nums[2], nums[3], stops),
+            _ => Paint::Radial(nums[0], nums[1], nums[2], nums[3], nums[4], nums[5], stops),
+        })
+    }
+
+    /// Number in paint
+    fn part(&self, part: Option<&str>) -> Result<f64, Error> {
+        part.and_then(|p| p.parse().ok()).ok_or_else(|| self.err())
+    }
+
+    fn path(&mut self) -> Result<Path, Error> {
+        let mut path = Path::new();
+        while let Some(op) = self.0.next() {
+            path.0.push(match op {
+                "M" => PathOp::MoveTo(self.num()?, self.num()?),
+                "L" => PathOp::LineTo(self.num()?, self.num()?),
+                "Q" => PathOp::QuadTo(self.num()?, self.num()?, self.num()?, self.num()?),
+                "C" => PathOp::CubicTo(
+                    self.num()?,
+                    self.num()?,
+                    self.num()?,
+                    self.num()?,
+                    self.num()?,
+                    self.num()?,
+                ),
+                "A" => PathOp::Arc(
+                    self.num()?,
+                    self.num()?,
+                    self.num()?,
+                    self.num()?,
+                    self.num()?,
+                    self.num()? != 0.0,
+                ),
+                "R" => PathOp::Rect(self.num()?, self.num()?, self.num()?, self.num()?),
+                "Z" => PathOp::Close,
+                _ => return Err(self.err()),
+            });
+        }
+
+        Ok(path)
+    }
+}
+
+impl FromStr for Drawing {
+    type Err = Error;
+
+    /// Parse drawing of `Drawing::to_string`
+    fn from_str(s: &str) -> Result<Drawing, Self::Err> {
+        let mut drawing = Drawing::new();
+        for line in s.lines().filter(|l| !l.trim().is_empty()) {
+            let mut t = Tokens(line.split_whitespace(), line);
+            drawing.0.push(match t.str()? {
+                "save" => Command::Save,
+                "restore" => Command::Restore,
+                "transform" => Command::Transform(Transform([
+                    t.num()?,
+                    t.num()?,
+                    t.num()?,
+                    t.num()?,
+                    t.num()?,
+                    t.num()?,
+                ])),
+                "fill" => {
+                    let paint = t.paint()?;
+                    Command::Fill(t.path()?, paint)
+                }
+                "stroke" => {
+                    let paint = t.paint()?;
+                    let width = t.num()?;
+                    Command::Stroke(t.path()?, paint, width)
+                }
+                "text" => {
+                    let paint = t.paint()?;
+                    let (x, y) = (t.num()?, t.num()?);
+                    let font = unescape(t.str()?);
+                    // Texts may be empty
+                    let text = unescape(t.0.next().unwrap_or_default());
+                    Command::Text(text, x, y, font, paint)
+                }
+                "region" => {
+                    let key = unescape(t.str()?);
+                    Command::Region(key, t.path()?)
+                }
+                _ => return Err(t.err()),
+            });
+        }
+
+        Ok(drawing)
+    }
+}
+
+/// Headless context, records calls for native tests
+#[derive(Clone, Debug, Default)]
+pub struct Recorder {
+    /// Recorded calls, like `fill(rgba(0, 0, 0, 1.0))`
+    pub calls: Vec<String>,
+}
+
+impl Recorder {
+    /// Record drawing
+    pub fn record(drawing: &Drawing) -> Recorder {
+        let mut recorder = Recorder::default();
+        drawing.draw(&mut recorder);
+        recorder
+    }
+}
+
+impl Context2d for Recorder {
+    fn save(&mut self) {
+        self.calls.push("save()".into());
+    }
+
+    fn restore(&mut self) {
+        self.calls.push("restore()".into());
+    }
+
+    fn transform(&mut self, t: &Transform) {
+        let [a, b, c, d, e, f] = t.0;
+        self.calls.push(format!(
+            "transform({}, {}, {}, {}, {}, {})",
+            a, b, c, d, e, f
+        ));
+    }
+
+    fn path(&mut self, path: &Path) {
+        self.calls.push("begin_path()".into());
+        for op in path.0.iter() {
+            self.calls.push(match op {
+                PathOp::MoveTo(x, y) => format!("move_to({}, {})", x, y),
+                PathOp::LineTo(x, y) => format!("line_to({}, {})", x, y),
+                PathOp::QuadTo(cx, cy, x, y) => {
+                    format!("quadratic_curve_to({}, {}, {}, {})", cx, cy, x, y)
+                }
+                PathOp::CubicTo(a, b, c, d, x, y) => {
+                    format!("bezier_curve_to({}, {}, {}, {}, {}, {})", a, b, c, d, x, y)
+                }
+                PathOp::Arc(x, y, r, s, e, ccw) => {
+                    format!("arc({}, {}, {}, {}, {}, {})", x, y, r, s, e, ccw)
+                }
+                PathOp::Rect(x, y, w, h) => format!("rect({}, {}, {}, {})", x, y, w, h),
+                PathOp::Close => "close_path()".into(),
+            });
+        }
+    }
+
+    fn fill(&mut self, paint: &Paint) {
+        self.calls.push(format!("fill({})", paint_to_string(paint)));
+    }
+
+    fn stroke(&mut self, paint: &Paint, width: f64) {
+        self.calls
+            .push(format!("stroke({}, {})", paint_to_string(paint), width));
+    }
+
+    fn text(&mut self, text: &str, x: f64, y: f64, font: &str, paint: &Paint) {
+        self.calls.push(format!(
+            "fill_text({:?}, {}, {}, {:?}, {})",
+            text,
+            x,
+            y,
+            font,
+            paint_to_string(paint)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Drawing, Paint, Path, Recorder, Transform};
+    use crate::value::Color;
+    use std::str::FromStr;
+
+    #[test]
+    fn round_trips_str() {
+        let drawing = Drawing::new()
+            .save()
+            .transform(Transform::translate(10.0, 20.0))
+            .fill_rect(0.0, 0.0, 40.0, 30.0, Color::Black)
+            .stroke(
+                Path::new().move_to(0.0, 0.0).line_to(5.0, 5.0).close(),
+                Paint::Linear(0.0, 0.0, 1.0, 1.0, vec![(0.0, Color::White)]),
+                2.0,
+            )
+            .restore()
+            .text(
+                "a\tb c\u{a0}d;e%20",
+                1.0,
+                2.0,
+                "12px sans-serif",
+                Color::Black,
+            )
+            .text("", 0.0, 0.0, "12px\nserif", Color::Black)
+            .region("bar 1", Path::new().circle(5.0, 5.0, 5.0));
+
+        assert_eq!(Drawing::from_str(&drawing.to_string()).ok(), Some(drawing));
+        assert!(Drawing::from_str("fill").is_err());
+    }
+
+    #[test]
+    fn hits_top_region() {
+        let drawing = Drawing::new()
+            .region("back", Path::new().rect(0.0, 0.0, 100.0, 100.0))
+            .save()
+            .transform(Transform::translate(50.0, 50.0))
+            .transform(Transform::scale(2.0, 2.0))
+            .region("front", Path::new().rect(0.0, 0.0, 10.0, 10.0))
+            .restore()
+            .region("side", Path::new().rect(200.0, 0.0, 10.0, 10.0));
+
+        assert_eq!(drawing.hit(10.0, 10.0), Some("back".into()));
+        assert_eq!(drawing.hit(65.0, 65.0), Some("front".into()));
+        assert_eq!(drawing.hit(205.0, 5.0), Some("side".into()));
+        assert_eq!(drawing.hit(150.0, 150.0), None);
+    }
+
+    #[test]
+    fn records_calls() {
+        let drawing = Drawing::new()
+            .fill_rect(0.0, 0.0, 4.0, 2.0, Color::Black)
+            .region("r", Path::new().rect(0.0, 0.0, 4.0, 2.0));
+
+        assert_eq!(
+            Recorder::record(&drawing).calls,
+            vec![
+                "begin_path()".to_string(),
+                "rect(0, 0, 4, 2)".to_string(),
+                format!("fill({})", Color::Black.to_string().replace(' ', "")),
+            ]
+        );
+    }
+}
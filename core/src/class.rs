@@ -1,4 +1,5 @@
 //! Elvis Class
+use crate::Node;
 
 /// Evlis classes
 #[derive(Clone, Eq, Ord, PartialEq, PartialOrd)]
@@ -11,18 +12,70 @@ pub enum Class {
     Row,
     /// Column Class
     Col,
+    /// Background Image Class
+    Image,
+    /// Named Class, register its styles with `Registry::class`
+    ///
+    /// `Class::from` maps names which are not css identifiers to `Empty`.
+    Named(String),
     /// Empty Class
     Empty,
 }
 
 impl From<&str> for Class {
     fn from(s: &str) -> Class {
-        match s {
+        match s.trim() {
             "center" => Class::Center,
             "flex" => Class::Flex,
             "row" => Class::Row,
             "col" => Class::Col,
+            "image" => Class::Image,
+            name if Class::is_ident(name) => Class::Named(name.to_string()),
             _ => Class::Empty,
         }
     }
 }
+
+impl Class {
+    /// If name is a css identifier, names which are not would break the
+    /// selectors of the sheet
+    pub fn is_ident(name: &str) -> bool {
+        let rest = name.strip_prefix('-').unwrap_or(name);
+        match rest.chars().next() {
+            Some(c) if c.is_ascii_digit() => false,
+            Some(_) => rest
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()),
+            None => false,
+        }
+    }
+}
+
+/// Reference classes by name
+pub trait ClassName {
+    /// Append class to node
+    fn class_name(self, name: &str) -> Node;
+}
+
+impl<T> ClassName for T
+where
+    T: Into<Node>,
+{
+    fn class_name(self, name: &str) -> Node {
+        self.into().append_class(&mut vec![Class::from(name)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Class;
+
+    #[test]
+    fn named_classes_are_identifiers() {
+        assert!(Class::from("card") == Class::Named("card".into()));
+        assert!(Class::from("-is_open-2") == Class::Named("-is_open-2".into()));
+        for name in &["two words", "a.b", "x>y", "2col", "-2col", "-", "a:hover"] {
+            assert!(Class::from(*name) == Class::Empty, "{}", name);
+        }
+    }
+}
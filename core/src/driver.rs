@@ -1,4 +1,5 @@
 //! Evlis platform api driver
+use crate::{Toast, Toasts};
 
 /// Elvis driver
 ///
@@ -8,4 +9,10 @@ pub trait Driver {
     fn alert(msg: &str);
     /// Common logger
     fn log(msg: &str);
+    /// Show toast, enqueues it for the toast host by default
+    ///
+    /// Headless drivers can capture toasts here instead.
+    fn notify(toast: Toast) -> usize {
+        Toasts::push(toast)
+    }
 }
@@ -8,6 +8,8 @@ pub enum Error {
     DeserializeHtmlError(String),
     /// Router Error
     RouterError(String),
+    /// Parse Error
+    ParseError(String),
     /// Custom Error
     Custom(String),
 }
@@ -0,0 +1,321 @@
+//! Form state
+//!
+//! `FormState` is a headless model of form fields, it tracks values, dirty
+//! and touched flags, runs validators and keeps the error messages.
+use crate::{Closure, Reactive, Subscription};
+use std::{collections::BTreeMap, future::Future, pin::Pin, sync::Arc};
+
+/// Sync validator, returns the error message if the value is invalid
+pub type Validator = Arc<dyn Fn(&str) -> Result<(), String>>;
+
+/// Async validator, returns the error message if the value is invalid
+pub type AsyncValidator = Arc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>>>>>;
+
+/// Form field
+#[derive(Clone, Default)]
+pub struct Field {
+    /// Current value
+    pub value: String,
+    /// Initial value
+    pub initial: String,
+    /// If the field has lost focus once
+    pub touched: bool,
+    /// Error message
+    pub error: Option<String>,
+    validators: Vec<Validator>,
+    async_validators: Vec<AsyncValidator>,
+}
+
+impl Field {
+    /// If the value differs from the initial one
+    pub fn dirty(&self) -> bool {
+        self.value != self.initial
+    }
+
+    /// The error shown, only touched fields show errors
+    fn shown(&self) -> Option<&String> {
+        self.error.as_ref().filter(|_| self.touched)
+    }
+
+    /// Run sync validators, keep the first error
+    fn validate(&mut self) -> bool {
+        self.error = self.validators.iter().find_map(|v| v(&self.value).err());
+        self.error.is_none()
+    }
+}
+
+/// Form state, clones share the same fields
+///
+/// Subscribers are notified when the shown errors change, on submit and on
+/// reset, writing values doesn't render again.
+#[derive(Clone, Default)]
+pub struct FormState {
+    fields: Reactive<BTreeMap<String, Field>>,
+}
+
+impl FormState {
+    /// New form state
+    pub fn new() -> FormState {
+        FormState::default()
+    }
+
+    /// Register field with initial value
+    pub fn field(self, name: &str, initial: impl Into<String>) -> Self {
+        let initial = initial.into();
+        self.fields.update_silent(|fs| {
+            fs.insert(
+                name.into(),
+                Field {
+                    value: initial.clone(),
+                    initial,
+                    ..Default::default()
+                },
+            );
+        });
+
+        self
+    }
+
+    /// Add sync validator to field
+    pub fn validator(
+        self,
+        name: &str,
+        validator: impl Fn(&str) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.fields.update_silent(|fs| {
+            fs.entry(name.into())
+                .or_default()
+                .validators
+                .push(Arc::new(validator));
+        });
+
+        self
+    }
+
+    /// Add async validator to field
+    pub fn async_validator<F>(self, name: &str, validator: impl Fn(String) -> F + 'static) -> Self
+    where
+        F: Future<Output = Result<(), String>> + 'static,
+    {
+        self.fields.update_silent(|fs| {
+            fs.entry(name.into())
+                .or_default()
+                .async_validators
+                .push(Arc::new(move |v| Box::pin(validator(v))));
+        });
+
+        self
+    }
+
+    /// Get field
+    pub fn get(&self, name: &str) -> Option<Field> {
+        self.fields.with(|fs| fs.get(name).cloned())
+    }
+
+    /// Get the value of field
+    pub fn value(&self, name: &str) -> String {
+        self.get(name).map(|f| f.value).unwrap_or_default()
+    }
+
+    /// Get all values
+    pub fn values(&self) -> BTreeMap<String, String> {
+        self.fields.with(|fs| {
+            fs.iter()
+                .map(|(k, f)| (k.clone(), f.value.clone()))
+                .collect()
+        })
+    }
+
+    /// Get the error of field, only touched fields have errors shown
+    pub fn error(&self, name: &str) -> Option<String> {
+        self.fields
+            .with(|fs| fs.get(name).and_then(|f| f.shown().cloned()))
+    }
+
+    /// Get all errors
+    pub fn errors(&self) -> BTreeMap<String, String> {
+        self.fields.with(|fs| {
+            fs.iter()
+                .filter_map(|(k, f)| f.error.clone().map(|e| (k.clone(), e)))
+                .collect()
+        })
+    }
+
+    /// Set the value of field and validate it, notifies if the shown error
+    /// changes
+    pub fn set(&self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        self.write(name, |field| field.value = value);
+    }
+
+    /// Mark field as touched and validate it, notifies if the shown error
+    /// changes
+    pub fn touch(&self, name: &str) {
+        self.write(name, |field| field.touched = true);
+    }
+
+    fn write(&self, name: &str, f: impl FnOnce(&mut Field)) {
+        let changed = self.fields.update_silent(|fs| {
+            let field = fs.entry(name.into()).or_default();
+            let shown = field.shown().cloned();
+            f(field);
+            field.validate();
+            field.shown() != shown.as_ref()
+        });
+
+        if changed {
+            self.fields.notify();
+        }
+    }
+
+    /// If any field is dirty
+    pub fn dirty(&self) -> bool {
+        self.fields.with(|fs| fs.values().any(|f| f.dirty()))
+    }
+
+    /// If all fields are valid
+    pub fn valid(&self) -> bool {
+        self.fields
+            .with(|fs| fs.values().all(|f| f.error.is_none()))
+    }
+
+    /// Touch all fields and run sync validators
+    pub fn validate(&self) -> bool {
+        let mut valid = true;
+        self.fields.update(|fs| {
+            for field in fs.values_mut() {
+                field.touched = true;
+                valid = field.validate() && valid;
+            }
+        });
+
+        valid
+    }
+
+    /// Run sync validators, then async validators of the valid fields
+    pub async fn validate_async(&self) -> bool {
+        if !self.validate() {
+            return false;
+        }
+
+        let pending = self.fields.with(|fs| {
+            fs.iter()
+                .map(|(k, f)| (k.clone(), f.value.clone(), f.async_validators.clone()))
+                .collect::<Vec<_>>()
+        });
+
+        let mut valid = true;
+        for (name, value, validators) in pending {
+            for validator in validators {
+                if let Err(e) = validator(value.clone()).await {
+                    self.fields.update(|fs| {
+                        if let Some(field) = fs.get_mut(&name) {
+                            field.error = Some(e);
+                        }
+                    });
+                    valid = false;
+                    break;
+                }
+            }
+        }
+
+        valid
+    }
+
+    /// Reset values to the initial ones and clear flags
+    pub fn reset(&self) {
+        self.fields.update(|fs| {
+            for field in fs.values_mut() {
+                field.value = field.initial.clone();
+                field.touched = false;
+                field.error = None;
+            }
+        });
+    }
+
+    /// Subscribe changes until the subscription drops
+    pub fn subscribe(&self, listener: Closure<()>) -> Subscription {
+        self.fields.subscribe(listener)
+    }
+
+    /// Take the fields of `old`, the form state replaced by this one
+    pub fn restore(&self, old: &FormState) {
+        self.fields.restore(&old.fields);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FormState;
+    use crate::Subscription;
+    use std::{cell::Cell, rc::Rc, sync::Arc};
+
+    /// Form with a required `name` and the count of notifications
+    fn form() -> (FormState, Rc<Cell<usize>>, Subscription) {
+        let form = FormState::new()
+            .field("name", "")
+            .validator("name", |v| {
+                if v.is_empty() {
+                    Err("required".into())
+                } else {
+                    Ok(())
+                }
+            });
+
+        let count = Rc::new(Cell::new(0));
+        let c = count.clone();
+        let sub = form.subscribe(Arc::new(move |_| c.set(c.get() + 1)));
+        (form, count, sub)
+    }
+
+    #[test]
+    fn set_writes_silently() {
+        let (form, count, _sub) = form();
+        assert_eq!(count.get(), 0);
+
+        form.set("name", "e");
+        form.set("name", "elvis");
+        assert_eq!(form.value("name"), "elvis");
+        assert!(form.dirty());
+        assert_eq!(count.get(), 0);
+
+        // untouched fields don't show errors
+        form.set("name", "");
+        assert_eq!(form.error("name"), None);
+        assert_eq!(count.get(), 0);
+    }
+
+    #[test]
+    fn touch_notifies_shown_errors() {
+        let (form, count, _sub) = form();
+        form.touch("name");
+        assert_eq!(form.error("name"), Some("required".into()));
+        assert_eq!(count.get(), 1);
+
+        // the error hides once the value is valid
+        form.set("name", "e");
+        form.set("name", "elvis");
+        assert_eq!(form.error("name"), None);
+        assert_eq!(count.get(), 2);
+
+        form.touch("name");
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn validate_touches_all_fields() {
+        let (form, count, _sub) = form();
+        assert!(!form.validate());
+        assert_eq!(form.errors().len(), 1);
+        assert_eq!(form.error("name"), Some("required".into()));
+        assert_eq!(count.get(), 1);
+
+        form.set("name", "elvis");
+        assert!(form.validate());
+        assert!(form.valid());
+
+        form.reset();
+        assert_eq!(form.value("name"), "");
+        assert_eq!(form.error("name"), None);
+    }
+}
@@ -20,6 +20,16 @@ macro_rules! construct_gesture {
 construct_gesture! {
     (Tap, "Trigger when tap widget"),
     (LongTap, "Trigger when long tap widget"),
+    (Input, "Trigger when the value of form control is changing, with `name`, `type`, `value` and `checked` in state"),
+    (Change, "Trigger when the value of form control changed, with `name`, `type`, `value` and `checked` in state"),
+    (Blur, "Trigger when form control loses focus, with `name` in state"),
+    (Submit, "Trigger when form submits, the default submit is prevented"),
+    (Load, "Trigger when image loaded"),
+    (Error, "Trigger when image failed to load"),
+    (Enter, "Trigger when pointer enters widget"),
+    (Leave, "Trigger when pointer leaves widget"),
+    (Key, "Trigger when key down, with `key` in state"),
+    (Scroll, "Trigger when scroll, with `scroll_top`, `scroll_left`, `client_height` and `client_width` in state, and the `heights` of the `data-index` items in `data-virtual` elements"),
 }
 
 /// Gesture HashMap
@@ -45,7 +55,7 @@ where
     }
 
     /// Register method
-    pub fn register(mut self, gesture: Gesture, callback: fn(StateKV) -> ()) -> Self {
+    pub fn register(mut self, gesture: Gesture, callback: impl Fn(StateKV) + 'static) -> Self {
         self.gesture
             .entry(gesture)
             .or_insert_with(|| Arc::new(callback));
@@ -82,8 +92,22 @@ where
 {
     fn into(self) -> Node {
         let mut n = self.child.into();
-        n.gesture = Some(self.gesture);
-        n.state = Some(HashMap::new());
+
+        // Keep the gestures of child, both run if registered twice
+        let mut gesture = n.gesture.take().unwrap_or_default();
+        for (g, f) in self.gesture {
+            let f = match gesture.remove(&g) {
+                Some(pre) => Arc::new(move |s: StateKV| {
+                    pre(s.clone());
+                    f(s);
+                }),
+                None => f,
+            };
+            gesture.insert(g, f);
+        }
+
+        n.gesture = Some(gesture);
+        n.state = n.state.or_else(|| Some(HashMap::new()));
         n
     }
 }
@@ -1,15 +1,23 @@
 //! Elvis shared library
 #![warn(missing_docs)]
 mod attr;
+mod canvas;
 mod class;
 mod closure;
 mod driver;
 mod err;
+mod form;
 mod gesture;
 mod life;
 mod node;
+mod placement;
+mod reactive;
+mod registry;
 mod router;
+mod sanitize;
+mod scroll;
 mod state;
+mod toast;
 mod wrapper;
 
 // Export module
@@ -18,16 +26,24 @@ pub mod style;
 pub mod value;
 
 pub use self::{
-    attr::Attribute,
-    class::Class,
+    attr::{Attribute, Namespace},
+    canvas::{Command, Context2d, Drawing, Paint, Path, PathOp, Recorder, Transform},
+    class::{Class, ClassName},
     closure::Closure,
     driver::Driver,
     err::Error,
+    form::{AsyncValidator, Field, FormState, Validator},
     gesture::{Gesture, GestureDetector, GestureKV},
-    life::LifeCycle,
+    life::{Component, LifeCycle},
     node::Node,
-    router::Router,
+    placement::{Placement, Rect},
+    reactive::{Reactive, Subscription},
+    registry::Registry,
+    router::{active_route, location, navigate, route, use_router, Router},
+    sanitize::{safe_url, sanitize},
+    scroll::{Axis, ScrollController, ScrollPosition, ScrollTarget},
     state::{State, StateKV},
     style::Style,
-    wrapper::StyleWrapper,
+    toast::{Severity, Stacking, Toast, Toasts},
+    wrapper::{Parent, StyleWrapper},
 };
@@ -1,8 +1,22 @@
 //! Life cycle
-use crate::Node;
+use crate::{Closure, Node, Subscription};
+use std::any::Any;
 
 /// Life cycle trait
 pub trait LifeCycle {
     /// Create widget
     fn create(&self) -> Node;
 }
+
+/// Stateful widget, implemented by `#[derive(Component)]`
+pub trait Component: LifeCycle {
+    /// Subscribe changes of the reactive state until the subscriptions drop
+    fn subscribe(&self, listener: Closure<()>) -> Vec<Subscription>;
+
+    /// Take the state of `old`, the component rendered at the same place
+    /// before, returns false if `old` is another component
+    fn restore(&self, old: &dyn Component) -> bool;
+
+    /// Downcast component
+    fn as_any(&self) -> &dyn Any;
+}
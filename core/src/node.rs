@@ -1,4 +1,4 @@
-use crate::{Attribute, Class, GestureKV, StateKV, Style, StyleWrapper};
+use crate::{Attribute, Class, Component, GestureKV, StateKV, Style, StyleWrapper};
 use elvis_core_support::Wrapper;
 use std::{
     cell::RefCell,
@@ -33,6 +33,8 @@ pub struct Node {
     pub state: Option<StateKV>,
     /// Node Gestures
     pub gesture: Option<GestureKV>,
+    /// The component rendered this node
+    pub component: Option<Rc<dyn Component>>,
 }
 
 impl fmt::Debug for Node {
@@ -45,6 +47,25 @@ impl fmt::Debug for Node {
 }
 
 impl Node {
+    /// Text node
+    pub fn text(text: &str) -> Node {
+        let mut node = Node::default();
+        node.attr.tag = "plain".into();
+        node.attr.text = text.into();
+        node
+    }
+
+    /// Render component and keep it in the node
+    pub fn from_component(c: Rc<dyn Component>) -> Node {
+        let mut node = c.create();
+        if node.component.is_some() {
+            node = Node::default().children(vec![node]);
+        }
+
+        node.component = Some(c);
+        node
+    }
+
     /// Set attribute
     pub fn attr(mut self, attr: Attribute) -> Node {
         self.attr = attr;
@@ -172,6 +193,36 @@ impl Node {
 
     /// Update tree
     pub fn update(&mut self) {}
+
+    /// Keep the state of the components in `old`, the tree replaced by `t`
+    ///
+    /// Components at the same place as one of the same type take its state
+    /// and render again.
+    pub fn restore(t: &Rc<RefCell<Node>>, old: &Node) {
+        let component = t.borrow().component.clone();
+        if let (Some(c), Some(o)) = (component, old.component.as_ref()) {
+            if !Rc::ptr_eq(&c, o) && c.restore(o.as_ref()) {
+                t.borrow_mut().replace(Node::from_component(c));
+            }
+        }
+
+        let children = t.borrow().children.clone();
+        for (child, old) in children.iter().zip(old.children.iter()) {
+            Node::restore(child, &old.borrow());
+        }
+    }
+}
+
+impl From<&str> for Node {
+    fn from(text: &str) -> Node {
+        Node::text(text)
+    }
+}
+
+impl From<String> for Node {
+    fn from(text: String) -> Node {
+        Node::text(&text)
+    }
 }
 
 impl PartialEq for Node {
@@ -190,3 +241,78 @@ impl PartialEq for Node {
 }
 
 impl Eq for Node {}
+
+#[cfg(test)]
+mod tests {
+    use super::Node;
+    use crate::{Closure, Component, LifeCycle, Reactive, Subscription};
+    use std::{any::Any, cell::RefCell, rc::Rc};
+
+    #[derive(Default)]
+    struct Counter {
+        count: Reactive<i32>,
+    }
+
+    impl LifeCycle for Counter {
+        fn create(&self) -> Node {
+            Node::text(&self.count.get().to_string())
+        }
+    }
+
+    impl Component for Counter {
+        fn subscribe(&self, listener: Closure<()>) -> Vec<Subscription> {
+            vec![self.count.subscribe(listener)]
+        }
+
+        fn restore(&self, old: &dyn Component) -> bool {
+            match old.as_any().downcast_ref::<Counter>() {
+                Some(old) => {
+                    self.count.restore(&old.count);
+                    true
+                }
+                None => false,
+            }
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    fn text(node: &Rc<RefCell<Node>>) -> String {
+        node.borrow().children[0].borrow().attr.text.clone()
+    }
+
+    #[test]
+    fn restore_keeps_nested_state() {
+        let old = Node::default().children(vec![Node::from_component(Rc::new(Counter::default()))]);
+        let counter = old.children[0].borrow().component.clone().unwrap();
+        counter
+            .as_any()
+            .downcast_ref::<Counter>()
+            .unwrap()
+            .count
+            .set(3);
+
+        let new = Rc::new(RefCell::new(
+            Node::default().children(vec![Node::from_component(Rc::new(Counter::default()))]),
+        ));
+        assert_eq!(text(&new), "0");
+
+        Node::restore(&new, &old);
+        assert_eq!(text(&new), "3");
+    }
+
+    #[test]
+    fn subscriptions_drop_listeners() {
+        let count = Reactive::new(0);
+        let calls = Rc::new(RefCell::new(0));
+        let c = calls.clone();
+        let sub = count.subscribe(std::sync::Arc::new(move |_| *c.borrow_mut() += 1));
+
+        count.set(1);
+        drop(sub);
+        count.set(2);
+        assert_eq!(*calls.borrow(), 1);
+    }
+}
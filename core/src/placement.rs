@@ -0,0 +1,221 @@
+//! Overlay placement
+//!
+//! Places floating elements next to their anchors, flips to the opposite side
+//! and shifts along the side to stay in the viewport.
+use std::fmt;
+
+/// Rectangle in px
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    /// Left offset
+    pub x: f64,
+    /// Top offset
+    pub y: f64,
+    /// Width
+    pub width: f64,
+    /// Height
+    pub height: f64,
+}
+
+impl Rect {
+    /// New rectangle
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// Side of anchor to place at
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Placement {
+    /// Above anchor
+    Top,
+    /// Below anchor
+    Bottom,
+    /// Left of anchor
+    Left,
+    /// Right of anchor
+    Right,
+}
+
+impl Default for Placement {
+    fn default() -> Placement {
+        Placement::Top
+    }
+}
+
+impl fmt::Display for Placement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Placement::Top => "top",
+            Placement::Bottom => "bottom",
+            Placement::Left => "left",
+            Placement::Right => "right",
+        })
+    }
+}
+
+impl From<&str> for Placement {
+    fn from(s: &str) -> Placement {
+        match s.trim() {
+            "bottom" => Placement::Bottom,
+            "left" => Placement::Left,
+            "right" => Placement::Right,
+            _ => Placement::Top,
+        }
+    }
+}
+
+impl Placement {
+    /// The opposite side
+    pub fn flip(self) -> Placement {
+        match self {
+            Placement::Top => Placement::Bottom,
+            Placement::Bottom => Placement::Top,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+        }
+    }
+
+    /// Space in the viewport on this side of anchor
+    fn space(self, anchor: &Rect, viewport: &Rect) -> f64 {
+        match self {
+            Placement::Top => anchor.y - viewport.y,
+            Placement::Bottom => viewport.y + viewport.height - anchor.y - anchor.height,
+            Placement::Left => anchor.x - viewport.x,
+            Placement::Right => viewport.x + viewport.width - anchor.x - anchor.width,
+        }
+    }
+
+    /// Place `size` next to `anchor` with `gap`, returns the final side and
+    /// the rectangle
+    pub fn place(
+        self,
+        anchor: Rect,
+        size: (f64, f64),
+        viewport: Rect,
+        gap: f64,
+    ) -> (Placement, Rect) {
+        let (width, height) = size;
+        let need = match self {
+            Placement::Top | Placement::Bottom => height + gap,
+            Placement::Left | Placement::Right => width + gap,
+        };
+
+        // Flip if the opposite side has more space
+        let side = if self.space(&anchor, &viewport) < need
+            && self.flip().space(&anchor, &viewport) > self.space(&anchor, &viewport)
+        {
+            self.flip()
+        } else {
+            self
+        };
+
+        let (x, y) = match side {
+            Placement::Top => (
+                anchor.x + (anchor.width - width) / 2.0,
+                anchor.y - height - gap,
+            ),
+            Placement::Bottom => (
+                anchor.x + (anchor.width - width) / 2.0,
+                anchor.y + anchor.height + gap,
+            ),
+            Placement::Left => (
+                anchor.x - width - gap,
+                anchor.y + (anchor.height - height) / 2.0,
+            ),
+            Placement::Right => (
+                anchor.x + anchor.width + gap,
+                anchor.y + (anchor.height - height) / 2.0,
+            ),
+        };
+
+        // Shift into viewport
+        let shift = |v: f64, min: f64, len: f64, size: f64| v.min(min + len - size).max(min);
+        (
+            side,
+            Rect::new(
+                shift(x, viewport.x, viewport.width, width),
+                shift(y, viewport.y, viewport.height, height),
+                width,
+                height,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Placement, Rect};
+
+    const VIEWPORT: Rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 800.0,
+        height: 600.0,
+    };
+
+    #[test]
+    fn places_at_side() {
+        let anchor = Rect::new(300.0, 300.0, 100.0, 40.0);
+        assert_eq!(
+            Placement::Top.place(anchor, (60.0, 20.0), VIEWPORT, 8.0),
+            (Placement::Top, Rect::new(320.0, 272.0, 60.0, 20.0))
+        );
+        assert_eq!(
+            Placement::Right.place(anchor, (60.0, 20.0), VIEWPORT, 8.0),
+            (Placement::Right, Rect::new(408.0, 310.0, 60.0, 20.0))
+        );
+    }
+
+    #[test]
+    fn flips_if_no_space() {
+        let anchor = Rect::new(300.0, 10.0, 100.0, 40.0);
+        assert_eq!(
+            Placement::Top.place(anchor, (60.0, 20.0), VIEWPORT, 8.0),
+            (Placement::Bottom, Rect::new(320.0, 58.0, 60.0, 20.0))
+        );
+
+        // Stays if the opposite side has less space
+        let anchor = Rect::new(300.0, 20.0, 100.0, 570.0);
+        assert_eq!(
+            Placement::Top.place(anchor, (60.0, 30.0), VIEWPORT, 8.0).0,
+            Placement::Top
+        );
+    }
+
+    #[test]
+    fn shifts_into_viewport() {
+        let anchor = Rect::new(770.0, 300.0, 20.0, 20.0);
+        let (side, rect) = Placement::Bottom.place(anchor, (100.0, 20.0), VIEWPORT, 8.0);
+        assert_eq!(side, Placement::Bottom);
+        assert_eq!(rect.x, 700.0);
+
+        let anchor = Rect::new(-50.0, 300.0, 20.0, 20.0);
+        assert_eq!(
+            Placement::Top
+                .place(anchor, (100.0, 20.0), VIEWPORT, 8.0)
+                .1
+                .x,
+            0.0
+        );
+    }
+
+    #[test]
+    fn round_trips_str() {
+        for side in &[
+            Placement::Top,
+            Placement::Bottom,
+            Placement::Left,
+            Placement::Right,
+        ] {
+            assert_eq!(Placement::from(side.to_string().as_str()), *side);
+            assert_eq!(side.flip().flip(), *side);
+        }
+        assert_eq!(Placement::from("nowhere"), Placement::Top);
+    }
+}
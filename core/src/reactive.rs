@@ -0,0 +1,137 @@
+//! Reactive cells
+use crate::Closure;
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    fmt,
+    rc::{Rc, Weak},
+};
+
+/// Listeners with their ids
+type Listeners = RefCell<BTreeMap<usize, Closure<()>>>;
+
+/// Reactive cell, notifies subscribers on change
+pub struct Reactive<T> {
+    value: Rc<RefCell<T>>,
+    listeners: Rc<Listeners>,
+    next: Rc<Cell<usize>>,
+}
+
+/// Subscription of reactive cell, the listener is removed when it drops
+#[must_use = "the listener is removed when the subscription drops"]
+pub struct Subscription {
+    id: usize,
+    listeners: Weak<Listeners>,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(listeners) = self.listeners.upgrade() {
+            listeners.borrow_mut().remove(&self.id);
+        }
+    }
+}
+
+impl fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Subscription").field(&self.id).finish()
+    }
+}
+
+impl<T> Reactive<T> {
+    /// New reactive cell
+    pub fn new(value: T) -> Reactive<T> {
+        Reactive {
+            value: Rc::new(RefCell::new(value)),
+            listeners: Rc::new(RefCell::new(BTreeMap::new())),
+            next: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Set value and notify subscribers
+    pub fn set(&self, value: T) {
+        *self.value.borrow_mut() = value;
+        self.notify();
+    }
+
+    /// Update value in place and notify subscribers
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        f(&mut self.value.borrow_mut());
+        self.notify();
+    }
+
+    /// Update value in place without notifying subscribers
+    pub fn update_silent<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self.value.borrow_mut())
+    }
+
+    /// Read value without cloning
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.value.borrow())
+    }
+
+    /// Subscribe changes until the subscription drops
+    pub fn subscribe(&self, listener: Closure<()>) -> Subscription {
+        let id = self.next.get();
+        self.next.set(id + 1);
+        self.listeners.borrow_mut().insert(id, listener);
+        Subscription {
+            id,
+            listeners: Rc::downgrade(&self.listeners),
+        }
+    }
+
+    /// Take the value of `old`, the cell replaced by this one
+    ///
+    /// Components rendered again keep the state of the ones they replace.
+    pub fn restore(&self, old: &Reactive<T>) {
+        if !Rc::ptr_eq(&self.value, &old.value) {
+            std::mem::swap(&mut *self.value.borrow_mut(), &mut *old.value.borrow_mut());
+        }
+    }
+
+    /// Notify subscribers
+    pub fn notify(&self) {
+        let listeners = self.listeners.borrow().values().cloned().collect::<Vec<_>>();
+        for l in listeners.iter() {
+            l(());
+        }
+    }
+}
+
+impl<T: Clone> Reactive<T> {
+    /// Get value
+    pub fn get(&self) -> T {
+        self.value.borrow().clone()
+    }
+}
+
+impl<T> Clone for Reactive<T> {
+    fn clone(&self) -> Self {
+        Reactive {
+            value: self.value.clone(),
+            listeners: self.listeners.clone(),
+            next: self.next.clone(),
+        }
+    }
+}
+
+impl<T: Default> Default for Reactive<T> {
+    fn default() -> Self {
+        Reactive::new(T::default())
+    }
+}
+
+impl<T> From<T> for Reactive<T> {
+    fn from(value: T) -> Self {
+        Reactive::new(value)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Reactive<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Reactive")
+            .field(&self.value.borrow())
+            .finish()
+    }
+}
@@ -0,0 +1,82 @@
+//! Shared resources registry
+use crate::{value::FontFace, Class, Style};
+use std::{cell::RefCell, collections::BTreeMap};
+
+thread_local! {
+    static FONTS: RefCell<Vec<FontFace>> = RefCell::new(vec![]);
+    static CLASSES: RefCell<BTreeMap<String, Vec<Style>>> = RefCell::new(BTreeMap::new());
+}
+
+/// Elvis registry
+///
+/// Stores resources shared by the whole app, platform drivers render them once.
+pub struct Registry;
+
+impl Registry {
+    /// Register font face
+    pub fn font(face: FontFace) {
+        FONTS.with(|fonts| {
+            let mut fonts = fonts.borrow_mut();
+            if !fonts.contains(&face) {
+                fonts.push(face);
+            }
+        });
+    }
+
+    /// Get registered font faces
+    pub fn fonts() -> Vec<FontFace> {
+        FONTS.with(|fonts| fonts.borrow().clone())
+    }
+
+    /// Register named class, reference it with `Class::Named`
+    ///
+    /// Names which are not css identifiers are ignored, see `Class::is_ident`.
+    pub fn class(name: &str, styles: impl Into<Vec<Style>>) {
+        if !Class::is_ident(name.trim()) {
+            return;
+        }
+
+        CLASSES.with(|classes| {
+            classes
+                .borrow_mut()
+                .insert(name.trim().to_string(), styles.into());
+        });
+    }
+
+    /// Get styles of named class
+    pub fn styles(name: &str) -> Option<Vec<Style>> {
+        CLASSES.with(|classes| classes.borrow().get(name).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Registry;
+    use crate::{
+        value::{Color, FontFace},
+        Style,
+    };
+
+    #[test]
+    fn registers_fonts_once() {
+        Registry::font(FontFace::with("a", "/a.woff2"));
+        Registry::font(FontFace::with("a", "/a.woff2"));
+        Registry::font(FontFace::with("b", "/b.woff2"));
+        assert_eq!(
+            Registry::fonts()
+                .iter()
+                .map(|f| f.family.as_str())
+                .collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test]
+    fn registers_named_classes() {
+        Registry::class(" card ", vec![Style::Color(Color::Red)]);
+        Registry::class("1card", vec![Style::Color(Color::Red)]);
+        assert!(Registry::styles("card").is_some());
+        assert!(Registry::styles("1card").is_none());
+        assert!(Registry::styles("missing").is_none());
+    }
+}
@@ -1,5 +1,17 @@
 //! Elvis Router
-use crate::Error;
+//!
+//! Navigation widgets follow `location()`, and push their routes with the
+//! router of `use_router`.
+use crate::{Error, Reactive};
+use std::cell::Cell;
+
+/// Push of the router in use
+type Push = fn(String) -> Result<(), Error>;
+
+thread_local! {
+    static LOCATION: Reactive<String> = Reactive::new("/".into());
+    static PUSH: Cell<Option<Push>> = Cell::new(None);
+}
 
 /// Elvis Router
 pub trait Router {
@@ -7,4 +19,94 @@ pub trait Router {
     // fn back() -> Result<(), Error>;
     /// Push new path
     fn push(path: impl Into<String>) -> Result<(), Error>;
+    /// Current path, the last navigated path by default
+    fn path() -> Result<String, Error> {
+        Ok(location().get())
+    }
+}
+
+/// Current path, components holding it render again when it changes
+pub fn location() -> Reactive<String> {
+    LOCATION.with(Reactive::clone)
+}
+
+/// Navigate with router `R`, starts at `R::path()`
+pub fn use_router<R: Router>() {
+    PUSH.with(|p| p.set(Some(|path| R::push(path))));
+    if let Ok(path) = R::path() {
+        let path = route(&path);
+        if location().get() != path {
+            location().set(path);
+        }
+    }
+}
+
+/// Push path with the router in use, and update location
+pub fn navigate(path: &str) -> Result<(), Error> {
+    if let Some(push) = PUSH.with(Cell::get) {
+        push(path.into())?;
+    }
+
+    location().set(route(path));
+    Ok(())
+}
+
+/// Normalize path, strips `.html`, `index` and the trailing `/`
+pub fn route(path: &str) -> String {
+    let path = path.trim_end_matches(".html").trim_matches('/');
+    let path = match path.rfind('/') {
+        Some(i) if &path[i + 1..] == "index" => &path[..i],
+        None if path == "index" => "",
+        _ => path,
+    };
+    format!("/{}", path)
+}
+
+/// The item whose route matches path, or the longest parent route of path
+pub fn active_route<'r>(routes: impl Iterator<Item = &'r str>, path: &str) -> Option<usize> {
+    let path = route(path);
+    routes
+        .enumerate()
+        .filter(|(_, r)| !r.is_empty())
+        .map(|(i, r)| (i, route(r)))
+        .filter(|(_, r)| {
+            r == &path || (r != "/" && path.starts_with(r) && path[r.len()..].starts_with('/'))
+        })
+        .max_by_key(|(_, r)| r.len())
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{location, navigate, use_router, Router};
+    use crate::Error;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static PUSHED: RefCell<Vec<String>> = RefCell::new(vec![]);
+    }
+
+    struct Memory;
+
+    impl Router for Memory {
+        fn push(path: impl Into<String>) -> Result<(), Error> {
+            PUSHED.with(|p| p.borrow_mut().push(path.into()));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn navigates_with_router() {
+        navigate("/docs/index.html").unwrap();
+        assert_eq!(location().get(), "/docs");
+        assert_eq!(Memory::path().ok(), Some("/docs".into()));
+
+        use_router::<Memory>();
+        navigate("/api").unwrap();
+        assert_eq!(location().get(), "/api");
+        assert_eq!(
+            PUSHED.with(|p| p.borrow().clone()),
+            vec!["/api".to_string()]
+        );
+    }
 }
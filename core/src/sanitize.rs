@@ -0,0 +1,387 @@
+//! Html sanitizer
+//!
+//! Keeps the common formatting tags and safe attributes, drops scripts,
+//! styles, embedded documents, event handlers and script urls.
+
+/// Tags to keep
+const TAGS: &[&str] = &[
+    "a",
+    "abbr",
+    "article",
+    "b",
+    "blockquote",
+    "br",
+    "caption",
+    "code",
+    "col",
+    "colgroup",
+    "dd",
+    "del",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "figcaption",
+    "figure",
+    "footer",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "i",
+    "img",
+    "ins",
+    "kbd",
+    "li",
+    "mark",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "q",
+    "s",
+    "section",
+    "small",
+    "span",
+    "strong",
+    "sub",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    "u",
+    "ul",
+];
+
+/// Tags to drop with their content
+const DROP: &[&str] = &[
+    "script", "style", "iframe", "object", "embed", "template", "noscript", "frame", "frameset",
+    "svg", "math",
+];
+
+/// Attributes to keep, and `aria-*`
+const ATTRS: &[&str] = &[
+    "href", "src", "alt", "title", "class", "width", "height", "colspan", "rowspan", "lang", "dir",
+    "start", "reversed", "cite", "datetime", "scope",
+];
+
+/// If url is safe, no scripts and only images in data urls
+pub fn safe_url(url: &str) -> bool {
+    let url = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_lowercase();
+
+    !(url.starts_with("javascript:")
+        || url.starts_with("vbscript:")
+        || (url.starts_with("data:") && !url.starts_with("data:image/")))
+}
+
+/// Decode the character references of attribute value, browsers decode them
+/// before reading urls
+fn unescape_attr(v: &str) -> String {
+    let mut res = String::with_capacity(v.len());
+    let mut rest = v;
+    while let Some(start) = rest.find('&') {
+        res.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+
+        // Numeric references, the `;` is optional
+        if let Some(r) = rest.strip_prefix('#') {
+            let (hex, r) = match r.strip_prefix('x').or_else(|| r.strip_prefix('X')) {
+                Some(r) => (true, r),
+                None => (false, r),
+            };
+            let digit = |c: char| {
+                if hex {
+                    c.is_ascii_hexdigit()
+                } else {
+                    c.is_ascii_digit()
+                }
+            };
+            let end = r.find(|c| !digit(c)).unwrap_or(r.len());
+            let code = u32::from_str_radix(&r[..end], if hex { 16 } else { 10 }).ok();
+            if let Some(c) = code.and_then(std::char::from_u32) {
+                res.push(c);
+                rest = r[end..].strip_prefix(';').unwrap_or(&r[end..]);
+                continue;
+            }
+        }
+
+        let named = [
+            ("amp;", '&'),
+            ("lt;", '<'),
+            ("gt;", '>'),
+            ("quot;", '"'),
+            ("apos;", '\''),
+            ("colon;", ':'),
+            ("tab;", '\t'),
+            ("newline;", '\n'),
+        ];
+        match named
+            .iter()
+            .find(|(n, _)| rest.get(..n.len()).map(str::to_ascii_lowercase).as_deref() == Some(n))
+        {
+            Some((n, c)) => {
+                res.push(*c);
+                rest = &rest[n.len()..];
+            }
+            None => res.push('&'),
+        }
+    }
+
+    res.push_str(rest);
+    res
+}
+
+/// Escape attribute value
+fn escape_attr(v: &str) -> String {
+    v.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Tag parsed from `<...>`
+struct Tag {
+    name: String,
+    closing: bool,
+    attrs: Vec<(String, String)>,
+    self_closing: bool,
+}
+
+impl Tag {
+    /// Parse the inside of `<...>`
+    fn parse(s: &str) -> Tag {
+        let s = s.trim();
+        let (closing, s) = match s.strip_prefix('/') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let self_closing = s.ends_with('/');
+        let s = s.trim_end_matches('/');
+
+        // `/` separates attributes as whitespace, `<img/src=x>`
+        let separator = |c: char| c.is_whitespace() || c == '/';
+        let end = s.find(separator).unwrap_or(s.len());
+        let name = s[..end].to_lowercase();
+
+        let mut attrs = vec![];
+        let mut rest = s[end..].trim_start_matches(separator);
+        while !rest.is_empty() {
+            let end = rest
+                .find(|c: char| separator(c) || c == '=')
+                .unwrap_or(rest.len());
+            let key = rest[..end].to_lowercase();
+            rest = rest[end..].trim_start();
+
+            let mut value = String::new();
+            if let Some(r) = rest.strip_prefix('=') {
+                let r = r.trim_start();
+                let (v, r) = match r.chars().next() {
+                    Some(q) if q == '"' || q == '\'' => {
+                        let r = &r[1..];
+                        let end = r.find(q).unwrap_or(r.len());
+                        (&r[..end], &r[(end + 1).min(r.len())..])
+                    }
+                    _ => {
+                        let end = r.find(|c: char| c.is_whitespace()).unwrap_or(r.len());
+                        (&r[..end], &r[end..])
+                    }
+                };
+                value = unescape_attr(v);
+                rest = r.trim_start_matches(separator);
+            }
+
+            if !key.is_empty() {
+                attrs.push((key, value));
+            }
+        }
+
+        Tag {
+            name,
+            closing,
+            attrs,
+            self_closing,
+        }
+    }
+
+    /// Render with the safe attributes
+    fn render(&self) -> String {
+        if self.closing {
+            return format!("</{}>", self.name);
+        }
+
+        let mut res = format!("<{}", self.name);
+        for (k, v) in self.attrs.iter() {
+            if !(ATTRS.contains(&k.as_str()) || k.starts_with("aria-")) {
+                continue;
+            }
+            if (k == "href" || k == "src") && !safe_url(v) {
+                continue;
+            }
+
+            res.push_str(&format!(" {}=\"{}\"", k, escape_attr(v)));
+        }
+
+        if self.self_closing {
+            res.push_str(" /");
+        }
+        res.push('>');
+        res
+    }
+}
+
+/// Find the end of tag from `<`, skips `>` in quotes
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Sanitize html, keeps the safe tags and attributes
+pub fn sanitize(html: &str) -> String {
+    let mut res = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        res.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // Comments and declarations
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        // A single `<` in text
+        let next = rest[1..].chars().next();
+        if !next.map_or(false, |c| c.is_ascii_alphabetic() || c == '/') {
+            res.push_str("&lt;");
+            rest = &rest[1..];
+            continue;
+        }
+
+        // Unclosed tag is text
+        let end = match tag_end(rest) {
+            Some(end) => end,
+            None => {
+                res.push_str("&lt;");
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        let tag = Tag::parse(&rest[1..end]);
+        rest = &rest[end + 1..];
+
+        if DROP.contains(&tag.name.as_str()) {
+            // Only foreign elements close themselves, `<script/>` has content
+            let empty = tag.self_closing && ["svg", "math"].contains(&tag.name.as_str());
+            if !tag.closing && !empty {
+                let lower = rest.to_ascii_lowercase();
+                rest = match lower.find(&format!("</{}", tag.name)) {
+                    Some(close) => {
+                        let after = &rest[close..];
+                        tag_end(after).map_or("", |e| &after[e + 1..])
+                    }
+                    None => "",
+                };
+            }
+            continue;
+        }
+
+        if TAGS.contains(&tag.name.as_str()) {
+            res.push_str(&tag.render());
+        }
+    }
+
+    res.push_str(rest);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{safe_url, sanitize};
+
+    #[test]
+    fn keeps_safe_markup() {
+        assert_eq!(
+            sanitize("<p class=\"a\">Hi <b>there</b><br/></p>"),
+            "<p class=\"a\">Hi <b>there</b><br /></p>"
+        );
+        assert_eq!(
+            sanitize("<a href=\"/?a=1&amp;b=2\">q</a>"),
+            "<a href=\"/?a=1&amp;b=2\">q</a>"
+        );
+    }
+
+    #[test]
+    fn drops_scripts_and_styles() {
+        assert_eq!(sanitize("a<script>alert(1)</script>b"), "ab");
+        assert_eq!(sanitize("a<STYLE>p { color: red }</Style >b"), "ab");
+        assert_eq!(sanitize("a<script/>alert(1)</script>b"), "ab");
+        assert_eq!(sanitize("a<script>alert(1)"), "a");
+        assert_eq!(sanitize("a<svg/>b"), "ab");
+    }
+
+    #[test]
+    fn drops_script_urls() {
+        for href in &[
+            "javascript:alert(1)",
+            " JavaScript:alert(1)",
+            "java\tscript:alert(1)",
+            "java&#x09;script:alert(1)",
+            "&#106;avascript:alert(1)",
+            "&#x6A&#x61vascript:alert(1)",
+            "javascript&colon;alert(1)",
+            "data:text/html,<script>alert(1)</script>",
+        ] {
+            assert_eq!(sanitize(&format!("<a href=\"{}\">x</a>", href)), "<a>x</a>");
+        }
+
+        assert!(safe_url("https://a.b/c"));
+        assert!(safe_url("data:image/png;base64,AA"));
+    }
+
+    #[test]
+    fn drops_event_handlers() {
+        assert_eq!(
+            sanitize("<b onclick=\"alert(1)\" title=t onMouseOver='x'>b</b>"),
+            "<b title=\"t\">b</b>"
+        );
+        assert_eq!(sanitize("<img/src=x onerror=alert(1)>"), "<img src=\"x\">");
+        assert_eq!(
+            sanitize("<img src=\"x\" alt='a\"><script>'>"),
+            "<img src=\"x\" alt=\"a&quot;&gt;&lt;script&gt;\">"
+        );
+    }
+
+    #[test]
+    fn escapes_unclosed_tags_and_drops_comments() {
+        assert_eq!(sanitize("a < b"), "a &lt; b");
+        assert_eq!(sanitize("a <b"), "a &lt;b");
+        assert_eq!(sanitize("a<!-- <script>alert(1)</script> -->b"), "ab");
+        assert_eq!(sanitize("a<!-- unclosed"), "a");
+        assert_eq!(sanitize("<!DOCTYPE html>a"), "a");
+    }
+}
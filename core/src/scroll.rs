@@ -0,0 +1,267 @@
+//! Scroll controller
+//!
+//! `ScrollController` tracks the offsets of a scroll view, requests scrolls,
+//! and calls the scroll and load more listeners.
+use crate::{Closure, Reactive, Subscription};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    sync::Arc,
+};
+
+/// Scroll axis
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Axis {
+    /// Scrolls vertically
+    Vertical,
+    /// Scrolls horizontally
+    Horizontal,
+    /// Scrolls in both axes
+    Both,
+}
+
+impl Default for Axis {
+    fn default() -> Axis {
+        Axis::Vertical
+    }
+}
+
+/// Scroll offsets and sizes in px
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollPosition {
+    /// Offset from top
+    pub top: f64,
+    /// Offset from left
+    pub left: f64,
+    /// Height of the content
+    pub height: f64,
+    /// Width of the content
+    pub width: f64,
+    /// Height of the viewport
+    pub client_height: f64,
+    /// Width of the viewport
+    pub client_width: f64,
+}
+
+impl ScrollPosition {
+    /// Space left to scroll in axis, the smaller one in both axes
+    pub fn remaining(&self, axis: Axis) -> f64 {
+        let vertical = (self.height - self.client_height - self.top).max(0.0);
+        let horizontal = (self.width - self.client_width - self.left).max(0.0);
+        match axis {
+            Axis::Vertical => vertical,
+            Axis::Horizontal => horizontal,
+            Axis::Both => vertical.min(horizontal),
+        }
+    }
+}
+
+/// Scroll request of controller
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScrollTarget {
+    /// Scroll to offsets, `(top, left)`
+    Offset(f64, f64),
+    /// Scroll to the descendant with `data-key`
+    Key(String),
+}
+
+/// Load more listener with its threshold
+struct LoadMore {
+    threshold: f64,
+    listener: Closure<()>,
+    /// Content size when the listener was called
+    fired: Cell<f64>,
+}
+
+/// Scroll controller, clones share the same scroll view
+#[derive(Clone, Default)]
+pub struct ScrollController {
+    position: Rc<Cell<ScrollPosition>>,
+    request: Rc<RefCell<Option<(ScrollTarget, bool)>>>,
+    version: Reactive<usize>,
+    listeners: Rc<RefCell<Vec<Closure<ScrollPosition>>>>,
+    load_more: Rc<RefCell<Vec<LoadMore>>>,
+}
+
+impl ScrollController {
+    /// New scroll controller
+    pub fn new() -> ScrollController {
+        ScrollController::default()
+    }
+
+    /// Current position
+    pub fn position(&self) -> ScrollPosition {
+        self.position.get()
+    }
+
+    /// Offset from top
+    pub fn offset(&self) -> f64 {
+        self.position.get().top
+    }
+
+    /// Scroll to offsets, smoothly if `smooth`
+    pub fn scroll_to(&self, top: f64, left: f64, smooth: bool) {
+        self.request(ScrollTarget::Offset(top, left), smooth);
+    }
+
+    /// Scroll to the descendant with `data-key`, see `Attribute::key`
+    pub fn scroll_to_key(&self, key: &str, smooth: bool) {
+        self.request(ScrollTarget::Key(key.into()), smooth);
+    }
+
+    fn request(&self, target: ScrollTarget, smooth: bool) {
+        *self.request.borrow_mut() = Some((target, smooth));
+        self.version.update(|v| *v += 1);
+    }
+
+    /// Take the pending request, scroll views call it while rendering
+    pub fn take_request(&self) -> Option<(ScrollTarget, bool)> {
+        self.request.borrow_mut().take()
+    }
+
+    /// Listen scroll events
+    pub fn on_scroll(self, f: impl Fn(ScrollPosition) + 'static) -> Self {
+        self.listeners.borrow_mut().push(Arc::new(f));
+        self
+    }
+
+    /// Call `f` if the space left to scroll is less than `threshold` px, once
+    /// per content size
+    pub fn on_load_more(self, threshold: f64, f: impl Fn() + 'static) -> Self {
+        self.load_more.borrow_mut().push(LoadMore {
+            threshold,
+            listener: Arc::new(move |_| f()),
+            fired: Cell::new(-1.0),
+        });
+        self
+    }
+
+    /// Report scroll, scroll views call it on scroll events
+    ///
+    /// Updating the position doesn't render again.
+    pub fn scrolled(&self, position: ScrollPosition, axis: Axis) {
+        self.position.set(position);
+        let listeners = self.listeners.borrow().clone();
+        for l in listeners.iter() {
+            l(position);
+        }
+
+        let size = match axis {
+            Axis::Horizontal => position.width,
+            _ => position.height,
+        };
+        let due = self
+            .load_more
+            .borrow()
+            .iter()
+            .filter(|m| position.remaining(axis) <= m.threshold && m.fired.get() != size)
+            .map(|m| {
+                m.fired.set(size);
+                m.listener.clone()
+            })
+            .collect::<Vec<_>>();
+        for f in due {
+            f(());
+        }
+    }
+
+    /// Subscribe scroll requests until the subscription drops
+    pub fn subscribe(&self, listener: Closure<()>) -> Subscription {
+        self.version.subscribe(listener)
+    }
+
+    /// Take the position of `old`, the controller replaced by this one
+    pub fn restore(&self, old: &ScrollController) {
+        if !Rc::ptr_eq(&self.position, &old.position) {
+            self.position.set(old.position.get());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Axis, ScrollController, ScrollPosition, ScrollTarget};
+    use std::{cell::Cell, rc::Rc, sync::Arc};
+
+    /// 1000px content in 200px viewport, both axes
+    fn at(top: f64, left: f64) -> ScrollPosition {
+        ScrollPosition {
+            top,
+            left,
+            height: 1000.0,
+            width: 1000.0,
+            client_height: 200.0,
+            client_width: 200.0,
+        }
+    }
+
+    #[test]
+    fn remaining_of_axes() {
+        let p = at(700.0, 100.0);
+        assert_eq!(p.remaining(Axis::Vertical), 100.0);
+        assert_eq!(p.remaining(Axis::Horizontal), 700.0);
+        assert_eq!(p.remaining(Axis::Both), 100.0);
+        assert_eq!(at(900.0, 0.0).remaining(Axis::Vertical), 0.0);
+    }
+
+    #[test]
+    fn scrolled_updates_position_and_listeners() {
+        let seen = Rc::new(Cell::new(0.0));
+        let s = seen.clone();
+        let c = ScrollController::new().on_scroll(move |p| s.set(p.top));
+
+        c.scrolled(at(120.0, 0.0), Axis::Vertical);
+        assert_eq!(c.offset(), 120.0);
+        assert_eq!(c.position(), at(120.0, 0.0));
+        assert_eq!(seen.get(), 120.0);
+        assert_eq!(c.clone().offset(), 120.0);
+    }
+
+    #[test]
+    fn loads_more_once_per_size() {
+        let calls = Rc::new(Cell::new(0));
+        let n = calls.clone();
+        let c = ScrollController::new().on_load_more(50.0, move || n.set(n.get() + 1));
+
+        c.scrolled(at(700.0, 0.0), Axis::Vertical);
+        assert_eq!(calls.get(), 0);
+        c.scrolled(at(760.0, 0.0), Axis::Vertical);
+        c.scrolled(at(800.0, 0.0), Axis::Vertical);
+        assert_eq!(calls.get(), 1);
+
+        // More content loaded
+        let mut p = at(1760.0, 0.0);
+        p.height = 2000.0;
+        c.scrolled(p, Axis::Vertical);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn loads_more_horizontally() {
+        let calls = Rc::new(Cell::new(0));
+        let n = calls.clone();
+        let c = ScrollController::new().on_load_more(50.0, move || n.set(n.get() + 1));
+
+        c.scrolled(at(800.0, 0.0), Axis::Horizontal);
+        assert_eq!(calls.get(), 0);
+        c.scrolled(at(0.0, 800.0), Axis::Horizontal);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn takes_request_once() {
+        let renders = Rc::new(Cell::new(0));
+        let r = renders.clone();
+        let c = ScrollController::new();
+        let _s = c.subscribe(Arc::new(move |_| r.set(r.get() + 1)));
+
+        c.scroll_to(10.0, 0.0, true);
+        c.scroll_to_key("row-3", false);
+        assert_eq!(renders.get(), 2);
+        assert_eq!(
+            c.take_request(),
+            Some((ScrollTarget::Key("row-3".into()), false))
+        );
+        assert_eq!(c.take_request(), None);
+    }
+}
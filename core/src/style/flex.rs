@@ -45,19 +45,24 @@ impl Into<Vec<Style>> for FlexStyle {
     fn into(self) -> Vec<Style> {
         let mut styles: Vec<Style> = vec![];
 
-        // let align_style: Vec<Style> = self.align.into();
-        // vec![
-        //     align_style[0].clone(),
-        //     align_style[1].clone(),
-        //     self.basis.into(),
-        //     self.direction.into(),
-        //     Style::FlexGrow(self.grow),
-        //     Style::Order(self.order),
-        //     Style::FlexWrap(self.wrap),
-        // ]
         if let Some(v) = self.align {
             styles.append(&mut v.into());
         }
+        if let Some(v) = self.basis {
+            styles.push(Style::FlexBasis(v));
+        }
+        if let Some(v) = self.direction {
+            styles.push(Style::FlexDirection(v));
+        }
+        if let Some(v) = self.grow {
+            styles.push(Style::FlexGrow(v));
+        }
+        if let Some(v) = self.order {
+            styles.push(Style::Order(v));
+        }
+        if let Some(v) = self.wrap {
+            styles.push(Style::FlexWrap(v));
+        }
 
         styles
     }
@@ -4,7 +4,7 @@ use crate::value::{
         MultiColumnLineStyle,
     },
     BorderRadius, BorderStyle, BoxBorder, BoxShadow, Color, Display, FontFamily, FontStyle,
-    Position, TextAlign, Unit, VecUnit,
+    ObjectFit, Overflow, Position, Ratio, TextAlign, TextDecoration, Unit, VecUnit,
 };
 
 pub fn camel_snake(camel: &str) -> String {
@@ -120,6 +120,11 @@ construct_style! {[
     (Height, Unit, height, "Box Height"),
     (MaxWidth, Unit, max_width, "Box Max Width"),
     (MaxHeight, Unit, max_height, "Box Max Height"),
+    (MinWidth, Unit, min_width, "Box Min Width"),
+    (MinHeight, Unit, min_height, "Box Min Height"),
+    (AspectRatio, Ratio, aspect_ratio, "Box Aspect Ratio"),
+    (OverflowX, Overflow, overflow_x, "Horizontal Overflow"),
+    (OverflowY, Overflow, overflow_y, "Vertical Overflow"),
     (OutlineWidth, Unit, outline_width, "Box Outline Width"),
     (Top, Unit, top, "Box Top"),
     (Right, Unit, right, "Box Right"),
@@ -157,6 +162,7 @@ construct_style! {[
     (AlignItems, FlexPosition, align_items, "AlignItem Style"),
     (JustifyContent, FlexPosition, justify_content, "AlignItem Style"),
     (FlexGrow, Unit, flex_glow, "FlexGrow Style"),
+    (FlexShrink, Unit, flex_shrink, "FlexShrink Style"),
     (Order, Unit, order, "FlexOrder Style"),
 
     // Grid
@@ -171,6 +177,7 @@ construct_style! {[
     // Column
     (ColumnCount, Unit, column_count, "ColumnCount Style"),
     (ColumnGap, Unit, column_gap, "ColumnGap Style"),
+    (RowGap, Unit, row_gap, "RowGap Style"),
     (ColumnRuleColor, Color, column_rule_color, "ColumnRuleColor Style"),
     (ColumnRuleStyle, MultiColumnLineStyle, column_rule_style, "ColumnRuleStyle Style"),
 ], [
@@ -192,12 +199,15 @@ construct_style! {[
 
     // Typo
     (TextAlign, text_align, "Text Align"),
+    (TextDecoration, text_decoration, "Text Decoration"),
 
     // Box
     (BoxShadow, box_shadow, "Box Shadow"),
     (Position, position, "Box Position"),
+    (ObjectFit, object_fit, "Object Fit"),
 
     // border radius
     (BorderRadius, border_radius, "Border Radius"),
     (Display, display, "display"),
+    (Overflow, overflow, "Overflow"),
 ]}
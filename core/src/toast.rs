@@ -0,0 +1,267 @@
+//! Toast notifications
+//!
+//! `Toasts` queues toasts for the toast host of the app, `Driver::notify`
+//! enqueues them by default.
+use crate::{Closure, Reactive};
+use std::{cell::RefCell, collections::VecDeque, fmt, sync::Arc};
+
+thread_local! {
+    static VISIBLE: Reactive<Vec<Toast>> = Reactive::default();
+    static PENDING: RefCell<VecDeque<Toast>> = RefCell::new(VecDeque::new());
+    static STACKING: RefCell<Stacking> = RefCell::new(Stacking::Stack(3));
+    static ID: RefCell<usize> = RefCell::new(0);
+}
+
+/// Severity of toast
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    /// Information
+    Info,
+    /// Success
+    Success,
+    /// Warning
+    Warning,
+    /// Error, announced at once
+    Error,
+}
+
+impl Default for Severity {
+    fn default() -> Severity {
+        Severity::Info
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Info => "info",
+            Severity::Success => "success",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// How toasts stack
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stacking {
+    /// Show toasts at most, the others wait in queue
+    Stack(usize),
+    /// The new toast replaces the shown ones
+    Replace,
+}
+
+impl Default for Stacking {
+    fn default() -> Stacking {
+        Stacking::Stack(3)
+    }
+}
+
+/// Toast message
+#[derive(Clone)]
+pub struct Toast {
+    /// Id, assigned while enqueueing
+    pub id: usize,
+    /// Message
+    pub message: String,
+    /// Severity
+    pub severity: Severity,
+    /// Action label and handler, the toast is dismissed after the action
+    pub action: Option<(String, Closure<()>)>,
+    /// Milliseconds to show, shows until dismissed if none
+    pub duration: Option<u32>,
+}
+
+impl Toast {
+    /// New info toast shows 4 seconds
+    pub fn new(message: &str) -> Toast {
+        Toast {
+            id: 0,
+            message: message.into(),
+            severity: Severity::Info,
+            action: None,
+            duration: Some(4000),
+        }
+    }
+
+    /// Set severity
+    pub fn severity(mut self, severity: Severity) -> Toast {
+        self.severity = severity;
+        self
+    }
+
+    /// Set action button
+    pub fn action(mut self, label: &str, f: impl Fn() + 'static) -> Toast {
+        self.action = Some((label.into(), Arc::new(move |_| f())));
+        self
+    }
+
+    /// Set milliseconds to show
+    pub fn duration(mut self, ms: u32) -> Toast {
+        self.duration = Some(ms);
+        self
+    }
+
+    /// Show until dismissed
+    pub fn sticky(mut self) -> Toast {
+        self.duration = None;
+        self
+    }
+}
+
+impl fmt::Debug for Toast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Toast")
+            .field("id", &self.id)
+            .field("message", &self.message)
+            .field("severity", &self.severity)
+            .field("action", &self.action.as_ref().map(|(label, _)| label))
+            .field("duration", &self.duration)
+            .finish()
+    }
+}
+
+/// Toast queue
+///
+/// Stores the toasts of the app, the toast host shows the visible ones.
+pub struct Toasts;
+
+impl Toasts {
+    /// Enqueue toast, returns its id
+    pub fn push(mut toast: Toast) -> usize {
+        toast.id = ID.with(|id| {
+            *id.borrow_mut() += 1;
+            *id.borrow()
+        });
+
+        let id = toast.id;
+        match STACKING.with(|s| *s.borrow()) {
+            Stacking::Replace => {
+                PENDING.with(|p| p.borrow_mut().clear());
+                VISIBLE.with(|v| v.set(vec![toast]));
+            }
+            Stacking::Stack(max) => {
+                if VISIBLE.with(|v| v.with(|v| v.len())) < max.max(1) {
+                    VISIBLE.with(|v| v.update(|v| v.push(toast)));
+                } else {
+                    PENDING.with(|p| p.borrow_mut().push_back(toast));
+                }
+            }
+        }
+
+        id
+    }
+
+    /// Dismiss toast, the next one in queue shows
+    pub fn dismiss(id: usize) {
+        PENDING.with(|p| p.borrow_mut().retain(|t| t.id != id));
+        if !VISIBLE.with(|v| v.with(|v| v.iter().any(|t| t.id == id))) {
+            return;
+        }
+
+        let next = PENDING.with(|p| p.borrow_mut().pop_front());
+        VISIBLE.with(|v| {
+            v.update(|v| {
+                v.retain(|t| t.id != id);
+                v.extend(next);
+            })
+        });
+    }
+
+    /// Dismiss all toasts
+    pub fn clear() {
+        PENDING.with(|p| p.borrow_mut().clear());
+        VISIBLE.with(|v| v.set(vec![]));
+    }
+
+    /// Set how toasts stack
+    pub fn stacking(stacking: Stacking) {
+        STACKING.with(|s| *s.borrow_mut() = stacking);
+    }
+
+    /// Visible toasts, the toast host subscribes it
+    pub fn visible() -> Reactive<Vec<Toast>> {
+        VISIBLE.with(|v| v.clone())
+    }
+
+    /// Toasts waiting in queue
+    pub fn pending() -> Vec<Toast> {
+        PENDING.with(|p| p.borrow().iter().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Stacking, Toast, Toasts};
+    use crate::Driver;
+    use std::cell::RefCell;
+
+    /// Messages of toasts
+    fn messages(toasts: &[Toast]) -> Vec<String> {
+        toasts.iter().map(|t| t.message.clone()).collect()
+    }
+
+    #[test]
+    fn stacks_and_queues() {
+        Toasts::stacking(Stacking::Stack(2));
+        let ids = ["a", "b", "c"]
+            .iter()
+            .map(|m| Toasts::push(Toast::new(m)))
+            .collect::<Vec<_>>();
+        assert_eq!(messages(&Toasts::visible().get()), vec!["a", "b"]);
+        assert_eq!(messages(&Toasts::pending()), vec!["c"]);
+
+        Toasts::dismiss(ids[0]);
+        assert_eq!(messages(&Toasts::visible().get()), vec!["b", "c"]);
+        assert!(Toasts::pending().is_empty());
+
+        Toasts::dismiss(ids[0]);
+        assert_eq!(messages(&Toasts::visible().get()), vec!["b", "c"]);
+        Toasts::clear();
+        assert!(Toasts::visible().get().is_empty());
+    }
+
+    #[test]
+    fn replaces_shown_toasts() {
+        Toasts::stacking(Stacking::Replace);
+        let a = Toasts::push(Toast::new("a"));
+        let b = Toasts::push(Toast::new("b"));
+        assert_ne!(a, b);
+        assert_eq!(messages(&Toasts::visible().get()), vec!["b"]);
+        assert!(Toasts::pending().is_empty());
+
+        Toasts::dismiss(a);
+        assert_eq!(messages(&Toasts::visible().get()), vec!["b"]);
+        Toasts::dismiss(b);
+        assert!(Toasts::visible().get().is_empty());
+    }
+
+    thread_local! {
+        static CAPTURED: RefCell<Vec<Toast>> = RefCell::new(vec![]);
+    }
+
+    /// Driver captures toasts without host
+    struct Headless;
+
+    impl Driver for Headless {
+        fn alert(_: &str) {}
+
+        fn log(_: &str) {}
+
+        fn notify(toast: Toast) -> usize {
+            CAPTURED.with(|c| c.borrow_mut().push(toast));
+            0
+        }
+    }
+
+    #[test]
+    fn headless_drivers_capture_toasts() {
+        Headless::notify(Toast::new("saved").sticky());
+        assert!(Toasts::visible().get().is_empty());
+        CAPTURED.with(|c| {
+            let captured = c.borrow();
+            assert_eq!(messages(&captured), vec!["saved"]);
+            assert_eq!(captured[0].duration, None);
+        });
+    }
+}
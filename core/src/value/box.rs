@@ -1,6 +1,7 @@
 use super::{Color, Unit};
+use crate::Error;
 use elvis_core_support::EnumStyle;
-use std::cmp::Ordering;
+use std::{cmp::Ordering, convert::TryFrom, fmt};
 
 /// Box Shadow
 #[derive(Clone, PartialEq, Eq)]
@@ -74,6 +75,10 @@ pub enum Position {
     Absolute,
     /// Relative position
     Relative,
+    /// Fixed position
+    Fixed,
+    /// Sticky position
+    Sticky,
 }
 
 impl Default for Position {
@@ -81,3 +86,86 @@ impl Default for Position {
         Position::Relative
     }
 }
+
+/// Object Fit
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum ObjectFit {
+    /// Fill the box
+    Fill,
+    /// Keep ratio, fit in the box
+    Contain,
+    /// Keep ratio, cover the box
+    Cover,
+    /// Keep the original size
+    None,
+    /// The smaller one of `None` and `Contain`
+    ScaleDown,
+}
+
+impl ToString for ObjectFit {
+    fn to_string(&self) -> String {
+        match self {
+            ObjectFit::Fill => "fill",
+            ObjectFit::Contain => "contain",
+            ObjectFit::Cover => "cover",
+            ObjectFit::None => "none",
+            ObjectFit::ScaleDown => "scale-down",
+        }
+        .into()
+    }
+}
+
+/// Ratio of width to height, `16 / 9`
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Ratio(pub u32, pub u32);
+
+impl Default for Ratio {
+    fn default() -> Ratio {
+        Ratio(1, 1)
+    }
+}
+
+/// Positive ratio in thousandths, `1.5` is `3 / 2`
+impl TryFrom<f64> for Ratio {
+    type Error = Error;
+
+    fn try_from(r: f64) -> Result<Ratio, Error> {
+        let w = (r * 1000.0).round();
+        if !w.is_finite() || w < 1.0 || w > f64::from(u32::MAX) {
+            return Err(Error::ParseError(format!("invalid ratio `{}`", r)));
+        }
+
+        let gcd = |mut a: u32, mut b: u32| {
+            while b != 0 {
+                let t = a % b;
+                a = b;
+                b = t;
+            }
+            a.max(1)
+        };
+
+        let g = gcd(w as u32, 1000);
+        Ok(Ratio(w as u32 / g, 1000 / g))
+    }
+}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} / {}", self.0, self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ratio;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn ratio_of_positive_float() {
+        assert_eq!(Ratio::try_from(1.5).ok(), Some(Ratio(3, 2)));
+        assert_eq!(Ratio::try_from(0.25).ok(), Some(Ratio(1, 4)));
+        for r in &[0.0, -1.0, 0.0001, f64::NAN, f64::INFINITY] {
+            assert!(Ratio::try_from(*r).is_err());
+        }
+    }
+}
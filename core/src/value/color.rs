@@ -1,5 +1,5 @@
-use crate::style::Style;
-use std::cmp::Ordering;
+use crate::{style::Style, Error};
+use std::{cmp::Ordering, str::FromStr};
 
 // /// ORGB
 // #[derive(Clone, Copy, Debug)]
@@ -317,6 +317,82 @@ impl ToString for Color {
     }
 }
 
+impl FromStr for Color {
+    type Err = Error;
+
+    /// Parse `#rgb`, `#rrggbb`, `#rrggbbaa`, `rgb(..)`, `rgba(..)` and `inherit`
+    fn from_str(s: &str) -> Result<Color, Self::Err> {
+        let t = s.trim().to_ascii_lowercase();
+        let err = || Error::ParseError(format!("invalid color `{}`", s.trim()));
+        let channel = |h: &str| i16::from_str_radix(h, 16).map_err(|_| err());
+
+        let (o, r, g, b) = if t == "inherit" {
+            return Ok(Color::Inherit);
+        } else if t == "transparent" {
+            (0.0, 0, 0, 0)
+        } else if let Some(h) = t.strip_prefix('#') {
+            if !h.is_ascii() {
+                return Err(err());
+            }
+
+            match h.len() {
+                3 => (
+                    1.0,
+                    channel(&h[0..1])? * 17,
+                    channel(&h[1..2])? * 17,
+                    channel(&h[2..3])? * 17,
+                ),
+                6 | 8 => (
+                    if h.len() == 8 {
+                        channel(&h[6..8])? as f32 / 255.0
+                    } else {
+                        1.0
+                    },
+                    channel(&h[0..2])?,
+                    channel(&h[2..4])?,
+                    channel(&h[4..6])?,
+                ),
+                _ => return Err(err()),
+            }
+        } else if t.starts_with("rgb") && t.ends_with(')') {
+            let args = t[t.find('(').ok_or_else(err)? + 1..t.len() - 1]
+                .split(',')
+                .map(|a| a.trim().to_string())
+                .collect::<Vec<String>>();
+            if args.len() != 3 && args.len() != 4 {
+                return Err(err());
+            }
+
+            // Channels out of range are clamped like browsers do
+            let c = |a: &str| a.parse::<i16>().map(|c| c.clamp(0, 255)).map_err(|_| err());
+            (
+                match args.get(3) {
+                    Some(a) => a.parse::<f32>().map_err(|_| err())?.clamp(0.0, 1.0),
+                    None => 1.0,
+                },
+                c(&args[0])?,
+                c(&args[1])?,
+                c(&args[2])?,
+            )
+        } else {
+            return Err(err());
+        };
+
+        Ok(
+            match Color::from_hex(format!(
+                "0x{:02X}{:02X}{:02X}{:02X}",
+                (o * 255.0) as i16,
+                r,
+                g,
+                b
+            )) {
+                Color::ORGB(..) => Color::ORGB(o, r, g, b),
+                c => c,
+            },
+        )
+    }
+}
+
 impl Into<Style> for Color {
     fn into(self) -> Style {
         Style::Color(self)
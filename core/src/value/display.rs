@@ -9,6 +9,8 @@ pub enum Display {
     Flex,
     /// Grid Display
     Grid,
+    /// Contents Display, children join the layout of parent
+    Contents,
 }
 
 impl ToString for Display {
@@ -18,6 +20,32 @@ impl ToString for Display {
             Display::InlineBlock => "inline-block",
             Display::Flex => "flex",
             Display::Grid => "grid",
+            Display::Contents => "contents",
+        }
+        .into()
+    }
+}
+
+/// Overflow
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Overflow {
+    /// Visible Overflow
+    Visible,
+    /// Hidden Overflow
+    Hidden,
+    /// Scroll Overflow
+    Scroll,
+    /// Auto Overflow
+    Auto,
+}
+
+impl ToString for Overflow {
+    fn to_string(&self) -> String {
+        match self {
+            Overflow::Visible => "visible",
+            Overflow::Hidden => "hidden",
+            Overflow::Scroll => "scroll",
+            Overflow::Auto => "auto",
         }
         .into()
     }
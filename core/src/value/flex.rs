@@ -22,7 +22,7 @@ impl ToString for FlexPosition {
             FlexPosition::End => "flex-end",
             FlexPosition::Start => "flex-start",
             FlexPosition::SpaceAround => "space-around",
-            FlexPosition::Between => "between",
+            FlexPosition::Between => "space-between",
         }
         .into()
     }
@@ -172,7 +172,7 @@ impl ToString for FlexWrap {
     fn to_string(&self) -> String {
         match self {
             FlexWrap::Wrap => "wrap",
-            FlexWrap::NoWrap => "no-wrap",
+            FlexWrap::NoWrap => "nowrap",
             FlexWrap::WrapReverse => "wrap-reverse",
         }
         .to_string()
@@ -1,3 +1,6 @@
+use crate::value::Unit;
+use elvis_core_support::{EnumStyle, Setter};
+
 /// Font Style
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug)]
 pub enum FontStyle {
@@ -26,6 +29,16 @@ impl From<&str> for FontStyle {
     }
 }
 
+/// Quote css string, escapes backslashes, quotes and newlines
+fn quote(s: &str) -> String {
+    format!(
+        "\"{}\"",
+        s.replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\a ")
+    )
+}
+
 /// Font Family
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
 pub enum FontFamily {
@@ -37,6 +50,16 @@ pub enum FontFamily {
     Neue,
     /// Arial Font
     Arial,
+    /// Any font family by name, for example "Helvetica Neue"
+    Named(String),
+    /// Generic serif family
+    Serif,
+    /// Generic sans-serif family
+    SansSerif,
+    /// Generic monospace family
+    Monospace,
+    /// Generic system-ui family
+    SystemUi,
     /// Derive Font Families
     Derive(Vec<FontFamily>),
 }
@@ -44,31 +67,171 @@ pub enum FontFamily {
 impl ToString for FontFamily {
     fn to_string(&self) -> String {
         match self {
-            FontFamily::Mix(a, b) => format!("\"{} {}\",", a.to_string(), b.to_string()),
+            FontFamily::Mix(a, b) => format!("\"{} {}\"", a.to_string(), b.to_string()),
             FontFamily::Helvetica => "Helvetica".to_string(),
             FontFamily::Neue => "Neue".to_string(),
             FontFamily::Arial => "Arial".to_string(),
-            FontFamily::Derive(v) => {
-                if v.len() == 1 && v[0].to_string().ends_with(',') {
-                    let s = v[0].to_string();
-                    s[..s.len() - 1].to_string()
-                } else {
-                    v.iter()
-                        .map(|f| f.to_string())
-                        .collect::<Vec<String>>()
-                        .join(" ")
-                }
-            }
+            FontFamily::Named(name) => quote(name),
+            FontFamily::Serif => "serif".to_string(),
+            FontFamily::SansSerif => "sans-serif".to_string(),
+            FontFamily::Monospace => "monospace".to_string(),
+            FontFamily::SystemUi => "system-ui".to_string(),
+            FontFamily::Derive(v) => v
+                .iter()
+                .map(|f| f.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
         }
     }
 }
 
 impl From<&str> for FontFamily {
     fn from(s: &str) -> FontFamily {
-        match s.to_lowercase().as_str() {
-            "Helvetica" => FontFamily::Helvetica,
-            "Neue" => FontFamily::Neue,
-            _ => FontFamily::Arial,
+        match s.trim().to_lowercase().as_str() {
+            "helvetica" => FontFamily::Helvetica,
+            "neue" => FontFamily::Neue,
+            "arial" => FontFamily::Arial,
+            "serif" => FontFamily::Serif,
+            "sans-serif" => FontFamily::SansSerif,
+            "monospace" => FontFamily::Monospace,
+            "system-ui" => FontFamily::SystemUi,
+            _ => FontFamily::Named(s.trim().trim_matches(|c| c == '"' || c == '\'').to_string()),
+        }
+    }
+}
+
+/// `font-display` descriptor of `@font-face`
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, EnumStyle)]
+pub enum FontDisplay {
+    /// Use the strategy of the user agent
+    Auto,
+    /// Short block period, infinite swap period
+    Block,
+    /// Extremely small block period, infinite swap period
+    Swap,
+    /// Extremely small block period, short swap period
+    Fallback,
+    /// Extremely small block period, no swap period
+    Optional,
+}
+
+impl Default for FontDisplay {
+    fn default() -> FontDisplay {
+        FontDisplay::Swap
+    }
+}
+
+/// `@font-face` rule, register it with `Registry::font`
+#[derive(Clone, Default, Eq, PartialEq, Ord, PartialOrd, Debug, Setter)]
+pub struct FontFace {
+    /// Font family name
+    pub family: String,
+    /// Font files, `woff2` files are recommended
+    pub src: Vec<String>,
+    /// Font display
+    pub display: FontDisplay,
+    /// Font weight
+    pub weight: Option<Unit>,
+    /// Font style
+    pub style: Option<FontStyle>,
+    /// Unicode ranges, for example `U+0000-00FF`
+    pub unicode_range: Vec<String>,
+}
+
+impl FontFace {
+    /// The shortcut of `FontFace::new().family(..).src(vec![..])`
+    pub fn with(family: &str, src: &str) -> FontFace {
+        FontFace::new().family(family).src(vec![src.to_string()])
+    }
+
+    /// The format hint of font file
+    fn format(src: &str) -> Option<&'static str> {
+        match src.rsplit('.').next()?.to_lowercase().as_str() {
+            "woff2" => Some("woff2"),
+            "woff" => Some("woff"),
+            "ttf" => Some("truetype"),
+            "otf" => Some("opentype"),
+            _ => None,
         }
     }
+
+    /// Convert `FontFace` to css string
+    pub fn to_css(&self) -> String {
+        let mut rules = vec![
+            format!("font-family: {}", quote(&self.family)),
+            format!(
+                "src: {}",
+                self.src
+                    .iter()
+                    .map(|s| match FontFace::format(s) {
+                        Some(f) => format!("url({}) format(\"{}\")", quote(s), f),
+                        None => format!("url({})", quote(s)),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            format!("font-display: {}", self.display.to_string()),
+        ];
+
+        if let Some(weight) = self.weight {
+            rules.push(format!("font-weight: {}", weight.to_string()));
+        }
+
+        if let Some(style) = self.style {
+            rules.push(format!("font-style: {}", style.to_string()));
+        }
+
+        if !self.unicode_range.is_empty() {
+            rules.push(format!("unicode-range: {}", self.unicode_range.join(", ")));
+        }
+
+        format!(
+            "@font-face {{\n{}\n}}",
+            rules
+                .iter()
+                .map(|r| format!("  {};", r))
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FontDisplay, FontFace, FontFamily, FontStyle};
+    use crate::value::Unit;
+
+    #[test]
+    fn quotes_family_names() {
+        assert_eq!(FontFamily::from("a\"b").to_string(), "\"a\\\"b\"");
+        assert_eq!(FontFamily::from(" serif ").to_string(), "serif");
+    }
+
+    #[test]
+    fn font_face_to_css() {
+        let face = FontFace::with("Fira Sans", "/fonts/fira.woff2")
+            .display(FontDisplay::Optional)
+            .weight(Unit::None(700.0))
+            .style(FontStyle::Italic);
+        assert_eq!(
+            face.to_css(),
+            [
+                "@font-face {",
+                "  font-family: \"Fira Sans\";",
+                "  src: url(\"/fonts/fira.woff2\") format(\"woff2\");",
+                "  font-display: optional;",
+                "  font-weight: 700;",
+                "  font-style: italic;",
+                "}",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn escapes_font_face_strings() {
+        let css = FontFace::with("a\"b", "/x\").b.ttf\n").to_css();
+        assert!(css.contains("font-family: \"a\\\"b\";"));
+        assert!(css.contains("src: url(\"/x\\\").b.ttf\\a \");"));
+    }
 }
@@ -20,9 +20,9 @@ pub mod layouts {
 pub use {
     border::{BorderRadius, BorderStyle, BoxBorder},
     color::Color,
-    display::Display,
-    font::{FontFamily, FontStyle},
-    r#box::{BoxShadow, Position},
-    typo::TextAlign,
+    display::{Display, Overflow},
+    font::{FontDisplay, FontFace, FontFamily, FontStyle},
+    r#box::{BoxShadow, ObjectFit, Position, Ratio},
+    typo::{TextAlign, TextDecoration},
     unit::{Unit, VecUnit},
 };
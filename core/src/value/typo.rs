@@ -10,7 +10,7 @@ pub enum TextAlign {
     /// Align Right
     Right,
     /// Align Justify
-    Juistyfy,
+    Justify,
     /// Align Start
     Start,
     /// Align End
@@ -28,3 +28,28 @@ impl Default for TextAlign {
         TextAlign::Center
     }
 }
+
+/// Text Decoration
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum TextDecoration {
+    /// No decoration
+    None,
+    /// Underline
+    Underline,
+    /// Overline
+    Overline,
+    /// Line through
+    LineThrough,
+}
+
+impl ToString for TextDecoration {
+    fn to_string(&self) -> String {
+        match self {
+            TextDecoration::None => "none",
+            TextDecoration::Underline => "underline",
+            TextDecoration::Overline => "overline",
+            TextDecoration::LineThrough => "line-through",
+        }
+        .into()
+    }
+}
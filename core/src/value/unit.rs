@@ -115,16 +115,21 @@ impl FromStr for Unit {
     fn from_str(s: &str) -> Result<Unit, Self::Err> {
         let t = s.trim();
         let u = t
-            .find(|c: char| !c.is_numeric() && !c.eq(&'.'))
-            .unwrap_or(0);
+            .find(|c: char| !c.is_numeric() && !c.eq(&'.') && !c.eq(&'-') && !c.eq(&'+'))
+            .unwrap_or_else(|| t.len());
+
+        if t[..u].is_empty() {
+            return match t.to_ascii_lowercase().as_str() {
+                "auto" | "inherit" => Ok(Unit::Auto),
+                _ => Err(Error::ParseError(format!("invalid unit `{}`", t))),
+            };
+        }
 
         let v: f64 = t[..u]
-            .trim()
             .parse()
-            .unwrap_or_else(|_| t[u..].trim().parse().unwrap_or(1.0));
+            .map_err(|_| Error::ParseError(format!("invalid number `{}`", &t[..u])))?;
 
         Ok(match t[u..].trim().to_ascii_lowercase().as_str() {
-            "auto" | "inherit" => Unit::Auto,
             "ch" => Unit::Ch(v),
             "cm" => Unit::Cm(v),
             "dpcm" => Unit::Dpcm(v),
@@ -143,8 +148,9 @@ impl FromStr for Unit {
             "vmax" => Unit::Vmax(v),
             "vmin" => Unit::Vmin(v),
             "vw" => Unit::Vw(v),
-            "%" => Unit::Percent(t[..u].parse().unwrap_or(100.0)),
-            _ => Unit::None(v),
+            "%" => Unit::Percent(v),
+            "" => Unit::None(v),
+            unit => return Err(Error::ParseError(format!("unknown unit `{}`", unit))),
         })
     }
 }
@@ -172,7 +178,7 @@ impl ToString for Unit {
             Unit::Vmin(n) => format!("{:.1}vmin", n),
             Unit::Vw(n) => format!("{:.1}vw", n),
             Unit::Percent(n) => format!("{:.1}%", n),
-            Unit::None(n) => format!("{:.0}", n),
+            Unit::None(n) => n.to_string(),
         }
     }
 }
@@ -197,8 +203,55 @@ impl ToString for VecUnit {
     }
 }
 
+impl FromStr for VecUnit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<VecUnit, Self::Err> {
+        Ok(VecUnit(
+            s.split_whitespace()
+                .map(|u| u.parse())
+                .collect::<Result<Vec<Unit>, Error>>()?,
+        ))
+    }
+}
+
 impl From<Vec<Unit>> for VecUnit {
     fn from(vu: Vec<Unit>) -> VecUnit {
         VecUnit(vu)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Unit, VecUnit};
+    use std::str::FromStr;
+
+    #[test]
+    fn unit_round_trip() {
+        for s in &["auto", "1.5em", "100.0%", "16.0px", "2.0rem", "1.5", "700", "-8.0px"] {
+            assert_eq!(Unit::from_str(s).unwrap().to_string(), *s);
+        }
+    }
+
+    #[test]
+    fn unitless_fraction() {
+        assert_eq!(Unit::None(1.5).to_string(), "1.5");
+        assert_eq!(Unit::None(1.0).to_string(), "1");
+        assert_eq!(Unit::from_str("1.25").unwrap(), Unit::None(1.25));
+    }
+
+    #[test]
+    fn rejects_invalid_units() {
+        assert!(Unit::from_str("1.5foo").is_err());
+        assert!(Unit::from_str("wide").is_err());
+        assert!(Unit::from_str("1.2.3px").is_err());
+        assert!(VecUnit::from_str("8px 1x").is_err());
+    }
+
+    #[test]
+    fn vec_unit_round_trip() {
+        let units = VecUnit::from_str("8px 16px").unwrap();
+        assert_eq!(units.to_string(), "8.0px 16.0px");
+        assert!(VecUnit::from_str(&units.to_string()).unwrap() == units);
+    }
+}
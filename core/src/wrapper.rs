@@ -5,3 +5,9 @@ pub trait StyleWrapper {
     /// Converet widget to node
     fn wrap(self) -> Node;
 }
+
+/// Widgets which accept children, used by `rsx!`
+pub trait Parent {
+    /// Set the children of widget
+    fn with_children(self, children: Vec<Node>) -> Self;
+}
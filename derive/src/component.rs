@@ -0,0 +1,90 @@
+use crate::path::elvis;
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Error, Fields, Type};
+
+/// Derive `LifeCycle`, `Component` and `Into<Node>`, components render with
+/// their `render` method
+pub fn parse(input: TokenStream) -> TokenStream {
+    const STATE_FIELD: &str = "state";
+    let input = parse_macro_input!(input as DeriveInput);
+    let fields = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Error::new_spanned(&input.ident, "expected a struct with named fields")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    // `state` fields should be `Reactive<T>`, `FormState` or `ScrollController`
+    let mut states = vec![];
+    for field in fields
+        .iter()
+        .filter(|f| f.attrs.iter().any(|a| a.path.is_ident(STATE_FIELD)))
+    {
+        match &field.ty {
+            Type::Path(p)
+                if p.path
+                    .segments
+                    .last()
+                    .map(|s| {
+                        s.ident == "Reactive"
+                            || s.ident == "FormState"
+                            || s.ident == "ScrollController"
+                    })
+                    == Some(true) =>
+            {
+                states.push(&field.ident)
+            }
+            ty => {
+                return Error::new_spanned(
+                    ty,
+                    "`#[state]` fields should be `Reactive<T>`, `FormState` or `ScrollController`",
+                )
+                .to_compile_error()
+                .into()
+            }
+        }
+    }
+
+    let elvis = elvis();
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    TokenStream::from(quote! {
+        impl #impl_generics #elvis::prelude::LifeCycle for #name #ty_generics #where_clause {
+            fn create(&self) -> #elvis::prelude::Node {
+                Self::render(self)
+            }
+        }
+
+        impl #impl_generics #elvis::prelude::Component for #name #ty_generics #where_clause {
+            fn subscribe(&self, listener: #elvis::Closure<()>) -> Vec<#elvis::Subscription> {
+                vec![#(self.#states.subscribe(listener.clone())),*]
+            }
+
+            fn restore(&self, old: &dyn #elvis::prelude::Component) -> bool {
+                match old.as_any().downcast_ref::<Self>() {
+                    Some(_old) => {
+                        #(self.#states.restore(&_old.#states);)*
+                        true
+                    }
+                    None => false,
+                }
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        impl #impl_generics Into<#elvis::prelude::Node> for #name #ty_generics #where_clause {
+            fn into(self) -> #elvis::prelude::Node {
+                #elvis::prelude::Node::from_component(std::rc::Rc::new(self))
+            }
+        }
+    })
+}
@@ -0,0 +1,481 @@
+use crate::path::elvis;
+use elvis_core::value::{Color, FontFamily, Unit, VecUnit};
+use proc_macro::TokenStream;
+use proc_macro2::{Delimiter, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote};
+use std::str::FromStr;
+use syn::{Error, LitStr};
+
+/// The value kinds of css properties
+enum Kind {
+    Unit,
+    VecUnit,
+    Color,
+    FontFamily,
+    BoxBorder,
+    Keyword(&'static str, &'static [(&'static str, &'static str)]),
+}
+
+const FLEX_POSITION: &[(&str, &str)] = &[
+    ("center", "Center"),
+    ("flex-end", "End"),
+    ("end", "End"),
+    ("flex-start", "Start"),
+    ("start", "Start"),
+    ("space-around", "SpaceAround"),
+    ("space-between", "Between"),
+];
+
+const BORDER_STYLE: &[(&str, &str)] = &[
+    ("none", "None"),
+    ("hidden", "Hidden"),
+    ("dotted", "Dotted"),
+    ("dashed", "Dashed"),
+    ("solid", "Solid"),
+    ("double", "Double"),
+    ("groove", "Groove"),
+    ("ridge", "Ridge"),
+    ("inset", "Inset"),
+    ("outset", "Outset"),
+];
+
+const OVERFLOW: &[(&str, &str)] = &[
+    ("visible", "Visible"),
+    ("hidden", "Hidden"),
+    ("scroll", "Scroll"),
+    ("auto", "Auto"),
+];
+
+/// Css property to `Style` variant and the kind of its value
+fn property(name: &str) -> Option<(&'static str, Kind)> {
+    Some(match name {
+        "width" => ("Width", Kind::Unit),
+        "height" => ("Height", Kind::Unit),
+        "max-width" => ("MaxWidth", Kind::Unit),
+        "max-height" => ("MaxHeight", Kind::Unit),
+        "min-width" => ("MinWidth", Kind::Unit),
+        "min-height" => ("MinHeight", Kind::Unit),
+        "outline-width" => ("OutlineWidth", Kind::Unit),
+        "top" => ("Top", Kind::Unit),
+        "right" => ("Right", Kind::Unit),
+        "bottom" => ("Bottom", Kind::Unit),
+        "left" => ("Left", Kind::Unit),
+        "padding" => ("Padding", Kind::VecUnit),
+        "padding-top" => ("PaddingTop", Kind::Unit),
+        "padding-right" => ("PaddingRight", Kind::Unit),
+        "padding-bottom" => ("PaddingBottom", Kind::Unit),
+        "padding-left" => ("PaddingLeft", Kind::Unit),
+        "margin" => ("Margin", Kind::VecUnit),
+        "margin-top" => ("MarginTop", Kind::Unit),
+        "margin-right" => ("MarginRight", Kind::Unit),
+        "margin-bottom" => ("MarginBottom", Kind::Unit),
+        "margin-left" => ("MarginLeft", Kind::Unit),
+        "border" => ("Border", Kind::BoxBorder),
+        "border-top" => ("BorderTop", Kind::BoxBorder),
+        "border-right" => ("BorderRight", Kind::BoxBorder),
+        "border-bottom" => ("BorderBottom", Kind::BoxBorder),
+        "border-left" => ("BorderLeft", Kind::BoxBorder),
+        "border-style" => ("BorderStyle", Kind::Keyword("BorderStyle", BORDER_STYLE)),
+        "font-weight" => ("FontWeight", Kind::Unit),
+        "font-size" => ("FontSize", Kind::Unit),
+        "font-stretch" => ("FontStretch", Kind::Unit),
+        "font-family" => ("FontFamily", Kind::FontFamily),
+        "font-style" => (
+            "FontStyle",
+            Kind::Keyword("FontStyle", &[("italic", "Italic"), ("normal", "Normal")]),
+        ),
+        "line-height" => ("LineHeight", Kind::Unit),
+        "color" => ("Color", Kind::Color),
+        "background-color" => ("BackgroundColor", Kind::Color),
+        "align-items" => (
+            "AlignItems",
+            Kind::Keyword("layouts::FlexPosition", FLEX_POSITION),
+        ),
+        "justify-content" => (
+            "JustifyContent",
+            Kind::Keyword("layouts::FlexPosition", FLEX_POSITION),
+        ),
+        "flex-grow" => ("FlexGrow", Kind::Unit),
+        "flex-shrink" => ("FlexShrink", Kind::Unit),
+        "order" => ("Order", Kind::Unit),
+        "flex-direction" => (
+            "FlexDirection",
+            Kind::Keyword(
+                "layouts::FlexDirection",
+                &[
+                    ("column", "Column"),
+                    ("column-reverse", "ColumnReverse"),
+                    ("row", "Row"),
+                    ("row-reverse", "RowReverse"),
+                ],
+            ),
+        ),
+        "flex-wrap" => (
+            "FlexWrap",
+            Kind::Keyword(
+                "layouts::FlexWrap",
+                &[
+                    ("wrap", "Wrap"),
+                    ("nowrap", "NoWrap"),
+                    ("wrap-reverse", "WrapReverse"),
+                ],
+            ),
+        ),
+        "grid-column-gap" => ("GridColumnGap", Kind::Unit),
+        "grid-row-gap" => ("GridRowGap", Kind::Unit),
+        "column-count" => ("ColumnCount", Kind::Unit),
+        "column-gap" => ("ColumnGap", Kind::Unit),
+        "row-gap" => ("RowGap", Kind::Unit),
+        "column-rule-color" => ("ColumnRuleColor", Kind::Color),
+        "text-align" => (
+            "TextAlign",
+            Kind::Keyword(
+                "TextAlign",
+                &[
+                    ("center", "Center"),
+                    ("left", "Left"),
+                    ("right", "Right"),
+                    ("justify", "Justify"),
+                    ("start", "Start"),
+                    ("end", "End"),
+                    ("inherit", "Inherit"),
+                    ("initial", "Initial"),
+                    ("unset", "Unset"),
+                ],
+            ),
+        ),
+        "text-decoration" => (
+            "TextDecoration",
+            Kind::Keyword(
+                "TextDecoration",
+                &[
+                    ("none", "None"),
+                    ("underline", "Underline"),
+                    ("overline", "Overline"),
+                    ("line-through", "LineThrough"),
+                ],
+            ),
+        ),
+        "display" => (
+            "Display",
+            Kind::Keyword(
+                "Display",
+                &[
+                    ("block", "Block"),
+                    ("inline-block", "InlineBlock"),
+                    ("flex", "Flex"),
+                    ("grid", "Grid"),
+                    ("contents", "Contents"),
+                ],
+            ),
+        ),
+        "overflow" => ("Overflow", Kind::Keyword("Overflow", OVERFLOW)),
+        "overflow-x" => ("OverflowX", Kind::Keyword("Overflow", OVERFLOW)),
+        "overflow-y" => ("OverflowY", Kind::Keyword("Overflow", OVERFLOW)),
+        "object-fit" => (
+            "ObjectFit",
+            Kind::Keyword(
+                "ObjectFit",
+                &[
+                    ("fill", "Fill"),
+                    ("contain", "Contain"),
+                    ("cover", "Cover"),
+                    ("none", "None"),
+                    ("scale-down", "ScaleDown"),
+                ],
+            ),
+        ),
+        "position" => (
+            "Position",
+            Kind::Keyword(
+                "Position",
+                &[
+                    ("absolute", "Absolute"),
+                    ("relative", "Relative"),
+                    ("fixed", "Fixed"),
+                    ("sticky", "Sticky"),
+                ],
+            ),
+        ),
+        _ => return None,
+    })
+}
+
+/// Convert `Unit` to tokens
+fn unit(u: Unit) -> TokenStream2 {
+    let (name, v) = match u {
+        Unit::Auto => return quote! { value::Unit::Auto },
+        Unit::Ch(v) => ("Ch", v),
+        Unit::Cm(v) => ("Cm", v),
+        Unit::Dpi(v) => ("Dpi", v),
+        Unit::Dpcm(v) => ("Dpcm", v),
+        Unit::Dppx(v) => ("Dppx", v),
+        Unit::Em(v) => ("Em", v),
+        Unit::Fr(v) => ("Fr", v),
+        Unit::In(v) => ("In", v),
+        Unit::Mm(v) => ("Mm", v),
+        Unit::Pc(v) => ("Pc", v),
+        Unit::Pt(v) => ("Pt", v),
+        Unit::Px(v) => ("Px", v),
+        Unit::Q(v) => ("Q", v),
+        Unit::Rem(v) => ("Rem", v),
+        Unit::Vh(v) => ("Vh", v),
+        Unit::Vmax(v) => ("Vmax", v),
+        Unit::Vmin(v) => ("Vmin", v),
+        Unit::Vw(v) => ("Vw", v),
+        Unit::Percent(v) => ("Percent", v),
+        Unit::None(v) => ("None", v),
+    };
+
+    let name = format_ident!("{}", name);
+    quote! { value::Unit::#name(#v) }
+}
+
+/// Convert `Color` to tokens
+fn color(c: Color) -> TokenStream2 {
+    match c {
+        Color::Inherit => quote! { value::Color::Inherit },
+        Color::ORGB(o, r, g, b) => quote! { value::Color::ORGB(#o, #r, #g, #b) },
+        named => {
+            let name = format_ident!("{}", format!("{:?}", named));
+            quote! { value::Color::#name }
+        }
+    }
+}
+
+/// Convert `FontFamily` to tokens
+fn font_family(f: FontFamily) -> TokenStream2 {
+    match f {
+        FontFamily::Named(name) => quote! { value::FontFamily::Named(#name.into()) },
+        FontFamily::Derive(families) => {
+            let families = families.into_iter().map(font_family);
+            quote! { value::FontFamily::Derive(vec![#(#families),*]) }
+        }
+        FontFamily::Mix(a, b) => {
+            let (a, b) = (font_family(*a), font_family(*b));
+            quote! { value::FontFamily::Mix(Box::new(#a), Box::new(#b)) }
+        }
+        generic => {
+            let name = format_ident!("{}", format!("{:?}", generic));
+            quote! { value::FontFamily::#name }
+        }
+    }
+}
+
+/// Convert css value to the tokens of `Style` value
+fn value(kind: &Kind, v: &str) -> Result<TokenStream2, String> {
+    let err = |e: elvis_core::Error| match e {
+        elvis_core::Error::ParseError(e) => e,
+        e => format!("{:?}", e),
+    };
+    Ok(match kind {
+        Kind::Unit => unit(Unit::from_str(v).map_err(err)?),
+        Kind::VecUnit => {
+            let units = VecUnit::from_str(v).map_err(err)?.0.into_iter().map(unit);
+            quote! { value::VecUnit(vec![#(#units),*]) }
+        }
+        Kind::Color => color(Color::from_str(v).map_err(err)?),
+        Kind::FontFamily => font_family(FontFamily::Derive(
+            v.split(',').map(FontFamily::from).collect(),
+        )),
+        Kind::BoxBorder => {
+            let mut border = quote! { value::BoxBorder::new() };
+            for part in v.split_whitespace() {
+                border = if let Ok(u) = Unit::from_str(part) {
+                    let u = unit(u);
+                    quote! { #border.width(#u) }
+                } else if let Ok(style) = value(&Kind::Keyword("BorderStyle", BORDER_STYLE), part) {
+                    quote! { #border.style(#style) }
+                } else {
+                    let c = color(Color::from_str(part).map_err(err)?);
+                    quote! { #border.color(#c) }
+                };
+            }
+            border
+        }
+        Kind::Keyword(ty, keywords) => {
+            let variant = keywords
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(v.trim()))
+                .map(|(_, variant)| format_ident!("{}", variant))
+                .ok_or_else(|| {
+                    format!(
+                        "invalid value `{}`, expected one of {}",
+                        v.trim(),
+                        keywords
+                            .iter()
+                            .map(|(k, _)| format!("`{}`", k))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )
+                })?;
+            let ty = TokenStream2::from_str(&format!("value::{}", ty)).expect("invalid value path");
+            quote! { #ty::#variant }
+        }
+    })
+}
+
+/// Join tokens to css text
+fn text(tokens: &[TokenTree]) -> String {
+    let mut s = String::new();
+    let mut glue = true;
+    for (i, t) in tokens.iter().enumerate() {
+        let next_is_literal = matches!(tokens.get(i + 1), Some(TokenTree::Literal(_)));
+        match t {
+            TokenTree::Punct(p) => match p.as_char() {
+                '%' | ',' => {
+                    s.push(p.as_char());
+                    glue = p.as_char() == '%';
+                }
+                '-' if !glue && next_is_literal => {
+                    s.push(' ');
+                    s.push('-');
+                    glue = true;
+                }
+                '#' | '-' | '.' => {
+                    if !glue && p.as_char() != '-' {
+                        s.push(' ');
+                    }
+                    s.push(p.as_char());
+                    glue = true;
+                }
+                c => {
+                    s.push(c);
+                    glue = false;
+                }
+            },
+            TokenTree::Group(g) => {
+                let delimiter = match g.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+                s.push_str(delimiter.0);
+                s.push_str(&text(&g.stream().into_iter().collect::<Vec<TokenTree>>()));
+                s.push_str(delimiter.1);
+                glue = false;
+            }
+            other => {
+                if !glue {
+                    s.push(' ');
+                }
+                s.push_str(&other.to_string());
+                glue = false;
+            }
+        }
+    }
+
+    s
+}
+
+/// Css declaration, `(property, property span, value, value span)`
+type Declaration = (String, Span, String, Span);
+
+/// Split css tokens into declarations
+fn declarations(tokens: &[TokenTree]) -> Result<Vec<Declaration>, Error> {
+    // The whole css in a string literal
+    if let [TokenTree::Literal(l)] = tokens {
+        let css = syn::parse_str::<LitStr>(&l.to_string())
+            .map_err(|_| Error::new(l.span(), "expected css declarations"))?
+            .value();
+        return css
+            .split(';')
+            .filter(|decl| !decl.trim().is_empty())
+            .map(|decl| match decl.find(':') {
+                Some(colon) => Ok((
+                    decl[..colon].trim().to_string(),
+                    l.span(),
+                    decl[colon + 1..].trim().to_string(),
+                    l.span(),
+                )),
+                None => Err(Error::new(l.span(), "expected `property: value`")),
+            })
+            .collect();
+    }
+
+    let mut decls = vec![];
+    for decl in tokens.split(|t| matches!(t, TokenTree::Punct(p) if p.as_char() == ';')) {
+        if decl.is_empty() {
+            continue;
+        }
+
+        let colon = decl
+            .iter()
+            .position(|t| matches!(t, TokenTree::Punct(p) if p.as_char() == ':'))
+            .ok_or_else(|| Error::new(decl[0].span(), "expected `property: value`"))?;
+
+        let v = &decl[colon + 1..];
+        let value = match v {
+            // Values can not be tokenized by rust, like `1.5em`, are quoted
+            [TokenTree::Literal(l)] => match syn::parse_str::<LitStr>(&l.to_string()) {
+                Ok(s) => s.value(),
+                Err(_) => text(v),
+            },
+            _ => text(v),
+        };
+
+        decls.push((
+            text(&decl[..colon]).replace(' ', ""),
+            decl[0].span(),
+            value,
+            v.first().map(|t| t.span()).unwrap_or_else(Span::call_site),
+        ));
+    }
+
+    Ok(decls)
+}
+
+/// Parse css declarations into `Vec<Style>`
+pub fn parse(input: TokenStream) -> TokenStream {
+    let tokens = TokenStream2::from(input)
+        .into_iter()
+        .collect::<Vec<TokenTree>>();
+    let decls = match declarations(&tokens) {
+        Ok(decls) => decls,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+
+    let elvis = elvis();
+    let mut styles: Vec<TokenStream2> = vec![];
+    let mut errors: Option<Error> = None;
+    let mut push_error = |e: Error| match &mut errors {
+        Some(errors) => errors.combine(e),
+        None => errors = Some(e),
+    };
+
+    for (name, name_span, v, value_span) in decls {
+        let (variant, kind) = match property(&name) {
+            Some(p) => p,
+            None => {
+                push_error(Error::new(
+                    name_span,
+                    format!("unknown css property `{}`", name),
+                ));
+                continue;
+            }
+        };
+
+        match value(&kind, &v) {
+            Ok(v) => {
+                let variant = format_ident!("{}", variant);
+                styles.push(quote! { #elvis::style::Style::#variant(#v) });
+            }
+            Err(e) => push_error(Error::new(
+                value_span,
+                format!("invalid value of `{}`: {}", name, e),
+            )),
+        }
+    }
+
+    TokenStream::from(match errors {
+        Some(e) => {
+            let e = e.to_compile_error();
+            quote! {{ #e vec![] }}
+        }
+        None => quote! {{
+            use #elvis::value;
+            vec![#(#styles),*]
+        }},
+    })
+}
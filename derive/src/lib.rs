@@ -2,8 +2,12 @@
 #![warn(missing_docs)]
 extern crate proc_macro;
 
+mod component;
+mod css;
 mod node;
 mod page;
+mod path;
+mod rsx;
 
 use proc_macro::TokenStream;
 
@@ -13,8 +17,126 @@ pub fn page(_attr: TokenStream, item: TokenStream) -> TokenStream {
     page::parse(_attr, item)
 }
 
+/// Derive `LifeCycle`, `Component` and `Into<Node>` for widgets with a
+/// `render` method
+///
+/// Fields marked `#[prop]` get setters from `Setter`, fields marked `#[state]`
+/// are `Reactive` cells, components render again when they change, and keep
+/// their state when their parents render again.
+///
+/// ```
+/// use elvis::{
+///     gesture::{Gesture, GestureDetector},
+///     prelude::*,
+///     widgets::Text,
+/// };
+///
+/// #[derive(Default, Setter, Component)]
+/// struct Counter {
+///     #[prop]
+///     label: String,
+///     #[state]
+///     count: Reactive<i32>,
+/// }
+///
+/// impl Counter {
+///     fn render(&self) -> Node {
+///         let count = self.count.clone();
+///         GestureDetector::new(Text::with(&format!("{}: {}", self.label, count.get())))
+///             .register(Gesture::Tap, move |_| count.update(|c| *c += 1))
+///             .into()
+///     }
+/// }
+///
+/// let node: Node = Counter::new().label("clicks").into();
+/// ```
+#[proc_macro_derive(Component, attributes(prop, state))]
+pub fn component(input: TokenStream) -> TokenStream {
+    component::parse(input)
+}
+
 /// Derive `Into<Node>`
-#[proc_macro_derive(IntoNode)]
+///
+/// Fields named `child`, `children` and `style` are used by default, mark the
+/// others with `#[elvis(child)]`, `#[elvis(children)]`, `#[elvis(style)]`,
+/// `#[elvis(class)]`, `#[elvis(attr)]` or `#[elvis(gesture)]`.
+///
+/// ```ignore
+/// #[derive(IntoNode)]
+/// #[elvis(tag = "section", class = "flex grid")]
+/// struct Gallery {
+///     #[elvis(children)]
+///     images: Vec<Image>,
+///     #[elvis(gesture)]
+///     gesture: GestureKV,
+///     style: GridStyle,
+/// }
+/// ```
+#[proc_macro_derive(IntoNode, attributes(elvis))]
 pub fn node(input: TokenStream) -> TokenStream {
     node::parse(input)
 }
+
+/// Parse css declarations into `Vec<Style>` at compile time
+///
+/// Values rust can not tokenize, like `1.5em`, should be quoted, or pass the
+/// whole css as a string literal.
+///
+/// ```
+/// use elvis::{prelude::*, style::Style};
+///
+/// let styles: Vec<Style> = css! {
+///     width: 100%;
+///     padding: 8px 16px;
+///     color: #2196F3;
+///     line-height: "1.5em";
+/// };
+/// assert_eq!(styles.len(), 4);
+///
+/// let styles: Vec<Style> = css!("width: 100%; line-height: 1.5;");
+/// assert_eq!(styles[1].to_css(), "line-height: 1.5");
+/// ```
+#[proc_macro]
+pub fn css(input: TokenStream) -> TokenStream {
+    css::parse(input)
+}
+
+/// Build `Node` trees with markup
+///
+/// Capitalized tags are widgets, their props map to setter methods, lowercase
+/// tags are raw html elements, strings are text. The props of raw tags set
+/// `id`, `src`, `href`, `text` and `type` of `Attribute`, others, like
+/// `aria-label` or `data-id`, become html attributes.
+///
+/// ```
+/// use elvis::{
+///     prelude::*,
+///     widgets::{
+///         layouts::{Col, Container},
+///         Text,
+///     },
+/// };
+///
+/// let (id, done, pending) = ("list", false, Text::with("pending"));
+/// let items = vec!["a", "b"];
+/// let callback = |_| println!("tapped");
+///
+/// let node: Node = rsx! {
+///     <Col>
+///         <Text text="hello" bold />
+///         <span>{format!("{} items", items.len())}</span>
+///         <div class="center" id={id}>
+///             for item in items {
+///                 <Text text={item} />
+///             }
+///             if done { "done" } else { {pending} }
+///         </div>
+///         <Container on:tap={callback}>"tap me"</Container>
+///         <button type="button" name="close" aria-label="Close" disabled>"×"</button>
+///     </Col>
+/// };
+/// ```
+#[proc_macro]
+pub fn rsx(input: TokenStream) -> TokenStream {
+    rsx::parse(input)
+}
@@ -1,71 +1,198 @@
+use crate::path::elvis;
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields};
+use syn::{
+    parse_macro_input, Attribute, Data, DataStruct, DeriveInput, Error, Fields, Ident, Lit, Meta,
+    NestedMeta, Result,
+};
 
-/// Single Child Widget
-pub fn parse(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
-    let struct_name = &input.ident;
-    let fields = match &input.data {
-        Data::Struct(DataStruct {
-            fields: Fields::Named(fields),
-            ..
-        }) => &fields.named,
-        _ => panic!("expected a struct with named fields"),
+const ELVIS_ATTR: &str = "elvis";
+const EXPECTED_PART: &str = "expected `child`, `children`, `style`, `class`, `attr` or `gesture`";
+
+/// Struct options, `#[elvis(tag = "section", class = "grid")]`
+#[derive(Default)]
+struct Options {
+    tag: Option<String>,
+    class: Option<Vec<String>>,
+}
+
+/// Fields which become parts of the node
+#[derive(Default)]
+struct Parts<'p> {
+    child: Option<&'p Ident>,
+    children: Option<&'p Ident>,
+    style: Option<&'p Ident>,
+    class: Option<&'p Ident>,
+    attr: Option<&'p Ident>,
+    gesture: Option<&'p Ident>,
+}
+
+/// Nested metas of `#[elvis(...)]`
+fn metas(attrs: &[Attribute]) -> Result<Vec<NestedMeta>> {
+    let mut res = vec![];
+    for attr in attrs.iter().filter(|a| a.path.is_ident(ELVIS_ATTR)) {
+        match attr.parse_meta()? {
+            Meta::List(list) => res.extend(list.nested),
+            meta => return Err(Error::new_spanned(meta, "expected `#[elvis(...)]`")),
+        }
     }
-    .iter()
-    .map(|field| field.ident.clone().unwrap().to_string())
-    .collect::<Vec<String>>();
-
-    TokenStream::from(
-        if fields.contains(&"child".to_string()) && fields.contains(&"style".to_string()) {
-            quote! {
-                impl Into<Node> for #struct_name {
-                    fn into(self) -> Node {
-                        Node::default()
-                            .children(vec![self.child])
-                            .class(vec![Class::Flex])
-                            .style(self.style.clone())
+
+    Ok(res)
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute]) -> Result<Options> {
+        let mut opts = Options::default();
+        for meta in metas(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::NameValue(nv)) => {
+                    let s = match &nv.lit {
+                        Lit::Str(s) => s.value(),
+                        lit => return Err(Error::new_spanned(lit, "expected a string literal")),
+                    };
+
+                    if nv.path.is_ident("tag") {
+                        opts.tag = Some(s);
+                    } else if nv.path.is_ident("class") {
+                        opts.class = Some(s.split_whitespace().map(|c| c.to_string()).collect());
+                    } else {
+                        return Err(Error::new_spanned(&nv.path, "expected `tag` or `class`"));
                     }
                 }
-            }
-        } else if fields.contains(&"child".to_string()) && !fields.contains(&"style".to_string()) {
-            quote! {
-                impl Into<Node> for #struct_name {
-                    fn into(self) -> Node {
-                        Node::default()
-                            .children(vec![self.child])
-                            .class(vec![Class::Flex])
-                    }
+                _ => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "expected `tag = \"...\"` or `class = \"...\"`",
+                    ))
                 }
             }
-        } else if fields.contains(&"style".to_string()) {
-            quote! {
-                impl Into<Node> for #struct_name {
-                    fn into(self) -> Node {
-                        Node::default()
-                            .children(self.children)
-                            .class(vec![
-                                Class::Flex,
-                                Class::from(stringify!($widget).to_lowercase().as_str()),
-                            ])
-                            .style(self.style)
+        }
+
+        Ok(opts)
+    }
+}
+
+impl<'p> Parts<'p> {
+    fn parse(fields: &'p Fields) -> Result<Parts<'p>> {
+        let mut parts = Parts::default();
+        for field in fields.iter() {
+            let ident = match &field.ident {
+                Some(ident) => ident,
+                None => continue,
+            };
+
+            let mut kinds = vec![];
+            for meta in metas(&field.attrs)? {
+                match &meta {
+                    NestedMeta::Meta(Meta::Path(p)) if p.get_ident().is_some() => {
+                        kinds.push(p.get_ident().unwrap().clone())
                     }
+                    _ => return Err(Error::new_spanned(meta, EXPECTED_PART)),
                 }
             }
-        } else {
-            quote! {
-                impl Into<Node> for #struct_name {
-                    fn into(self) -> Node {
-                        Node::default()
-                            .children(self.children)
-                            .class(vec![
-                                Class::Flex,
-                                Class::from(stringify!($widget).to_lowercase().as_str()),
-                            ])
-                    }
+            // Fields named as parts are parts by default
+            if kinds.is_empty()
+                && ["child", "children", "style"].contains(&ident.to_string().as_str())
+            {
+                kinds.push(ident.clone());
+            }
+
+            for kind in kinds {
+                let slot = match kind.to_string().as_str() {
+                    "child" => &mut parts.child,
+                    "children" => &mut parts.children,
+                    "style" => &mut parts.style,
+                    "class" => &mut parts.class,
+                    "attr" => &mut parts.attr,
+                    "gesture" => &mut parts.gesture,
+                    _ => return Err(Error::new_spanned(kind, EXPECTED_PART)),
+                };
+
+                if slot.is_some() {
+                    return Err(Error::new_spanned(
+                        kind.clone(),
+                        format!("duplicate `{}` field", kind),
+                    ));
                 }
+                *slot = Some(ident);
             }
+        }
+
+        if let (Some(_), Some(children)) = (parts.child, parts.children) {
+            return Err(Error::new_spanned(
+                children,
+                "`child` and `children` can not be used together",
+            ));
+        }
+
+        Ok(parts)
+    }
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let struct_name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(DataStruct { fields, .. }) => fields,
+        _ => return Err(Error::new_spanned(struct_name, "expected a struct")),
+    };
+
+    let opts = Options::parse(&input.attrs)?;
+    let parts = Parts::parse(fields)?;
+
+    let elvis = elvis();
+    let mut node = quote! { let mut node = #elvis::prelude::Node::default(); };
+    if let Some(attr) = parts.attr {
+        node.extend(quote! { node.attr = self.#attr; });
+    }
+    if let Some(tag) = opts.tag {
+        node.extend(quote! { node.attr.tag = #tag.into(); });
+    }
+
+    if let Some(child) = parts.child {
+        node.extend(quote! { node = node.children(vec![self.#child.into()]); });
+    } else if let Some(children) = parts.children {
+        node.extend(quote! {
+            node = node.children(self.#children.into_iter().map(Into::into).collect());
+        });
+    }
+
+    // Flex with the struct name for `children` by default
+    let class = match opts.class {
+        Some(class) => quote! { vec![#(#elvis::Class::from(#class)),*] },
+        None if parts.children.is_some() => quote! {
+            vec![
+                #elvis::Class::Flex,
+                #elvis::Class::from(stringify!(#struct_name).to_lowercase().as_str()),
+            ]
         },
-    )
+        None => quote! { vec![#elvis::Class::Flex] },
+    };
+    node.extend(quote! { node = node.class(#class); });
+    if let Some(class) = parts.class {
+        node.extend(quote! { node.class.extend(self.#class); });
+    }
+
+    if let Some(style) = parts.style {
+        node.extend(quote! { node = node.style(self.#style); });
+    }
+    if let Some(gesture) = parts.gesture {
+        node.extend(quote! { node.gesture = self.#gesture.into(); });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics Into<#elvis::prelude::Node> for #struct_name #ty_generics #where_clause {
+            fn into(self) -> #elvis::prelude::Node {
+                #node
+                node
+            }
+        }
+    })
+}
+
+/// Derive `Into<Node>` with `#[elvis(...)]` options
+pub fn parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(expand(&input).unwrap_or_else(|e| e.to_compile_error()))
 }
@@ -1,13 +1,106 @@
+use crate::path::elvis;
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, AttributeArgs, DeriveInput, Error, Lit, Meta, NestedMeta, Path};
+
+/// Arguments of `#[page]`
+#[derive(Default)]
+struct Args {
+    mount: Option<String>,
+    title: Option<String>,
+    name: Option<Ident>,
+    init: Option<Path>,
+    atomic: bool,
+}
+
+impl Args {
+    fn parse(args: AttributeArgs) -> Result<Args, Error> {
+        let mut res = Args::default();
+        for arg in args {
+            match arg {
+                NestedMeta::Meta(Meta::NameValue(nv)) => {
+                    let s = match &nv.lit {
+                        Lit::Str(s) => s,
+                        lit => return Err(Error::new_spanned(lit, "expected a string literal")),
+                    };
+
+                    // `route` is read by epm
+                    if nv.path.is_ident("route") {
+                        if !s.value().starts_with('/') {
+                            return Err(Error::new_spanned(s, "route should start with `/`"));
+                        }
+                    } else if nv.path.is_ident("mount") {
+                        res.mount = Some(s.value());
+                    } else if nv.path.is_ident("title") {
+                        res.title = Some(s.value());
+                    } else if nv.path.is_ident("name") {
+                        res.name = Some(s.parse::<Ident>().map_err(|_| {
+                            Error::new_spanned(s, "name should be a valid identifier")
+                        })?);
+                    } else if nv.path.is_ident("init") {
+                        res.init = Some(s.parse::<Path>()?);
+                    } else {
+                        return Err(Error::new_spanned(&nv.path, "unknown page argument"));
+                    }
+                }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("atomic") => res.atomic = true,
+                arg => {
+                    return Err(Error::new_spanned(
+                        arg,
+                        "expected `route`, `mount`, `title`, `name`, `init` or `atomic`",
+                    ))
+                }
+            }
+        }
+
+        Ok(res)
+    }
+}
 
 /// Basic elvis attr macro
-pub fn parse(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = match Args::parse(parse_macro_input!(attr as AttributeArgs)) {
+        Ok(args) => args,
+        Err(e) => return e.to_compile_error().into(),
+    };
     let item = parse_macro_input!(item as DeriveInput);
     let ident = item.ident.clone();
-    let fnn = Ident::new(&ident.to_string().to_lowercase(), Span::mixed_site());
+    let fnn = args
+        .name
+        .unwrap_or_else(|| Ident::new(&ident.to_string().to_lowercase(), Span::mixed_site()));
+
+    let elvis = elvis();
+    let mut page = quote! { #elvis::prelude::Page::from(#ident.create()) };
+    if let Some(mount) = args.mount {
+        page = quote! { #page.mount(#mount) };
+    }
+    if let Some(title) = args.title {
+        page = quote! { #page.title(#title) };
+    }
+    if args.atomic {
+        page = quote! { #page.atomic(true) };
+    }
+
+    let run = quote! {
+        if let Err(e) = #page.calling() {
+            <#elvis::Driver as #elvis::traits::DriverTrait>::log(&format!("{:?}", e));
+        }
+    };
+
+    let body = match args.init {
+        Some(init) => quote! {
+            #elvis::prelude::spawn_local(async {
+                if let Err(e) = #init().await {
+                    <#elvis::Driver as #elvis::traits::DriverTrait>::log(&format!("{:?}", e));
+                    return;
+                }
+
+                #run
+            });
+        },
+        None => run,
+    };
 
     let expanded = quote! {
         #item
@@ -15,8 +108,7 @@ pub fn parse(_attr: TokenStream, item: TokenStream) -> TokenStream {
         /// Run APP
         #[wasm_bindgen]
         pub fn #fnn() {
-            let mut page = Page::from(#ident.create());
-            page.calling().unwrap();
+            #body
         }
     };
 
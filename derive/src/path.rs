@@ -0,0 +1,27 @@
+//! Crate paths in generated code
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro_crate::crate_name;
+use quote::quote;
+use std::env;
+use syn::Ident;
+
+/// Path of crate `name` where the macro is called
+///
+/// `crate` inside the crate itself except its doctests, otherwise the name of
+/// the dependency in the `Cargo.toml` of the caller, which may be renamed.
+pub fn krate(name: &str) -> TokenStream2 {
+    let ident = name.replace('-', "_");
+    if env::var("CARGO_CRATE_NAME").ok().as_deref() == Some(ident.as_str())
+        && env::var("UNSTABLE_RUSTDOC_TEST_PATH").is_err()
+    {
+        return quote! { crate };
+    }
+
+    let name = Ident::new(&crate_name(name).unwrap_or(ident), Span::call_site());
+    quote! { ::#name }
+}
+
+/// Path of the `elvis` crate
+pub fn elvis() -> TokenStream2 {
+    krate("elvis")
+}
@@ -0,0 +1,360 @@
+use crate::path::elvis;
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{
+    braced,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    spanned::Spanned,
+    token, Error, Expr, Ident, Lit, LitStr, Pat, Path, Result, Token,
+};
+
+/// Element property, `name="value"`, `name={expr}` or `name`, raw tags also
+/// accept hyphenated names like `aria-label`
+struct Prop {
+    name: Ident,
+    attr: String,
+    value: Option<Expr>,
+}
+
+/// Gesture binding, `on:tap={callback}`, `gesture` is the variant of `Gesture`
+struct Bind {
+    gesture: Ident,
+    callback: Expr,
+}
+
+/// Markup element, `<Name props>children</Name>` or `<Name props />`
+struct Element {
+    name: Path,
+    props: Vec<Prop>,
+    binds: Vec<Bind>,
+    children: Vec<Child>,
+}
+
+/// Children of element
+enum Child {
+    Element(Element),
+    Text(LitStr),
+    Expr(Expr),
+    For(Pat, Expr, Vec<Child>),
+    If(Expr, Vec<Child>, Option<Vec<Child>>),
+}
+
+/// Parse the value of property
+fn value(input: ParseStream) -> Result<Expr> {
+    if input.peek(token::Brace) {
+        let content;
+        braced!(content in input);
+        content.parse()
+    } else {
+        Ok(Expr::Lit(syn::ExprLit {
+            attrs: vec![],
+            lit: input.parse::<Lit>()?,
+        }))
+    }
+}
+
+/// Parse children in braces
+fn block(input: ParseStream) -> Result<Vec<Child>> {
+    let content;
+    braced!(content in input);
+    let mut children = vec![];
+    while !content.is_empty() {
+        children.push(content.parse()?);
+    }
+
+    Ok(children)
+}
+
+impl Parse for Element {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<Token![<]>()?;
+        let name = input.parse::<Path>()?;
+
+        let mut props = vec![];
+        let mut binds = vec![];
+        while !input.peek(Token![>]) && !input.peek(Token![/]) {
+            let prop = Ident::parse_any(input)?;
+            if prop == "on" && input.peek(Token![:]) {
+                input.parse::<Token![:]>()?;
+                let gesture = input.parse::<Ident>()?;
+                let gesture = match gesture.to_string().as_str() {
+                    "tap" => Ident::new("Tap", gesture.span()),
+                    "long_tap" | "longtap" => Ident::new("LongTap", gesture.span()),
+                    other => {
+                        return Err(Error::new(
+                            gesture.span(),
+                            format!("unknown gesture `{}`, expected `tap` or `long_tap`", other),
+                        ))
+                    }
+                };
+                input.parse::<Token![=]>()?;
+                binds.push(Bind {
+                    gesture,
+                    callback: value(input)?,
+                });
+                continue;
+            }
+
+            let mut attr = prop.to_string();
+            while input.peek(Token![-]) {
+                if is_widget(&name) {
+                    return Err(Error::new(
+                        prop.span(),
+                        format!("expected setter of `{}`", path_name(&name)),
+                    ));
+                }
+                input.parse::<Token![-]>()?;
+                attr.push('-');
+                attr.push_str(&Ident::parse_any(input)?.to_string());
+            }
+
+            let value = if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                Some(value(input)?)
+            } else {
+                None
+            };
+            props.push(Prop {
+                name: prop,
+                attr,
+                value,
+            });
+        }
+
+        let mut children = vec![];
+        if input.peek(Token![/]) {
+            input.parse::<Token![/]>()?;
+            input.parse::<Token![>]>()?;
+        } else {
+            input.parse::<Token![>]>()?;
+            while !(input.peek(Token![<]) && input.peek2(Token![/])) {
+                if input.is_empty() {
+                    return Err(Error::new_spanned(
+                        &name,
+                        format!("unclosed element `{}`", path_name(&name)),
+                    ));
+                }
+                children.push(input.parse()?);
+            }
+
+            input.parse::<Token![<]>()?;
+            input.parse::<Token![/]>()?;
+            let close = input.parse::<Path>()?;
+            if path_name(&close) != path_name(&name) {
+                return Err(Error::new_spanned(
+                    close,
+                    format!("expected `</{}>`", path_name(&name)),
+                ));
+            }
+            input.parse::<Token![>]>()?;
+        }
+
+        Ok(Element {
+            name,
+            props,
+            binds,
+            children,
+        })
+    }
+}
+
+/// The single root element of `rsx!`
+struct Root(Element);
+
+impl Parse for Root {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let root = input.parse()?;
+        if !input.is_empty() {
+            return Err(input.error("expected a single root element"));
+        }
+
+        Ok(Root(root))
+    }
+}
+
+impl Parse for Child {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![<]) {
+            Ok(Child::Element(input.parse()?))
+        } else if input.peek(LitStr) {
+            Ok(Child::Text(input.parse()?))
+        } else if input.peek(token::Brace) {
+            let content;
+            braced!(content in input);
+            Ok(Child::Expr(content.parse()?))
+        } else if input.peek(Token![for]) {
+            input.parse::<Token![for]>()?;
+            let pat = input.parse::<Pat>()?;
+            input.parse::<Token![in]>()?;
+            let expr = Expr::parse_without_eager_brace(input)?;
+            Ok(Child::For(pat, expr, block(input)?))
+        } else if input.peek(Token![if]) {
+            input.parse::<Token![if]>()?;
+            let cond = Expr::parse_without_eager_brace(input)?;
+            let then = block(input)?;
+            let otherwise = if input.peek(Token![else]) {
+                input.parse::<Token![else]>()?;
+                if input.peek(Token![if]) {
+                    Some(vec![input.parse()?])
+                } else {
+                    Some(block(input)?)
+                }
+            } else {
+                None
+            };
+            Ok(Child::If(cond, then, otherwise))
+        } else {
+            Err(input.error("expected element, text, `{expr}`, `for` or `if`"))
+        }
+    }
+}
+
+fn path_name(path: &Path) -> String {
+    path.segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<String>>()
+        .join("::")
+}
+
+/// Widgets are capitalized, raw tags are not
+fn is_widget(path: &Path) -> bool {
+    path.segments.len() > 1
+        || path.segments[0]
+            .ident
+            .to_string()
+            .starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// Expand children into pushes onto `__children`
+fn pushes(elvis: &TokenStream2, children: &[Child]) -> TokenStream2 {
+    let pushes = children.iter().map(|child| match child {
+        Child::Element(e) => {
+            let e = element(elvis, e);
+            quote! { __children.push(#e); }
+        }
+        Child::Text(t) => quote! {
+            __children.push(#elvis::prelude::Node::text(#t));
+        },
+        Child::Expr(e) => quote_spanned! { e.span() =>
+            __children.push(Into::<#elvis::prelude::Node>::into(#e));
+        },
+        Child::For(pat, expr, body) => {
+            let body = pushes(elvis, body);
+            quote! { for #pat in #expr { #body } }
+        }
+        Child::If(cond, then, otherwise) => {
+            let then = pushes(elvis, then);
+            match otherwise {
+                Some(otherwise) => {
+                    let otherwise = pushes(elvis, otherwise);
+                    quote! { if #cond { #then } else { #otherwise } }
+                }
+                None => quote! { if #cond { #then } },
+            }
+        }
+    });
+
+    quote! { #(#pushes)* }
+}
+
+/// Expand element into `Node`
+fn element(elvis: &TokenStream2, e: &Element) -> TokenStream2 {
+    let name = &e.name;
+    let pushes = pushes(elvis, &e.children);
+    let children = quote! {{
+        let mut __children: Vec<#elvis::prelude::Node> = vec![];
+        #pushes
+        __children
+    }};
+
+    let mut node = if is_widget(name) {
+        let setters = e.props.iter().map(|p| {
+            let prop = &p.name;
+            match &p.value {
+                Some(v) => quote! { .#prop(#v) },
+                None => quote! { .#prop(true) },
+            }
+        });
+        let with_children = if e.children.is_empty() {
+            quote! {}
+        } else {
+            quote! { let __widget = #elvis::traits::Parent::with_children(__widget, #children); }
+        };
+
+        quote! {
+            let __widget = #name::new() #(#setters)*;
+            #with_children
+            let mut __node: #elvis::prelude::Node = __widget.into();
+        }
+    } else {
+        let tag = path_name(name);
+        let props = e.props.iter().map(|p| {
+            let prop = &p.name;
+            let v = match &p.value {
+                Some(v) => quote! { #v },
+                None => quote! { "" },
+            };
+
+            if prop == "style" {
+                quote! { __node.style = (#v).into(); }
+            } else if prop == "class" {
+                match &p.value {
+                    Some(Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(s), ..
+                    })) => {
+                        let names = s.value();
+                        let names = names.split_whitespace();
+                        quote! {
+                            #(__node = #elvis::traits::ClassName::class_name(__node, #names);)*
+                        }
+                    }
+                    _ => quote! {
+                        __node = #elvis::traits::ClassName::class_name(__node, (#v).as_ref());
+                    },
+                }
+            } else if prop == "type" {
+                quote! { __node.attr.r#type = (#v).into(); }
+            } else if ["id", "src", "href", "text"].contains(&p.attr.as_str()) {
+                quote! { __node.attr.#prop = (#v).into(); }
+            } else {
+                let attr = &p.attr;
+                quote! { __node.attr = __node.attr.prop(#attr, #v); }
+            }
+        });
+
+        quote! {
+            let mut __node = #elvis::prelude::Node::default();
+            __node.attr.tag = #tag.into();
+            #(#props)*
+            __node = __node.children(#children);
+        }
+    };
+
+    if !e.binds.is_empty() {
+        let registers = e.binds.iter().map(|b| {
+            let gesture = &b.gesture;
+            let callback = &b.callback;
+            quote! { .register(#elvis::gesture::Gesture::#gesture, #callback) }
+        });
+
+        node = quote! {
+            #node
+            __node = #elvis::gesture::GestureDetector::new(__node) #(#registers)* .into();
+        };
+    }
+
+    quote! {{
+        #node
+        __node
+    }}
+}
+
+/// Parse markup into `Node`
+pub fn parse(input: TokenStream) -> TokenStream {
+    let root = parse_macro_input!(input as Root);
+    TokenStream::from(element(&elvis(), &root.0))
+}
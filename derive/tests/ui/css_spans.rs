@@ -0,0 +1,10 @@
+use elvis::{prelude::*, style::Style};
+
+fn main() {
+    let _: Vec<Style> = css! {
+        heigth: 10px;
+        color: "blue-ish";
+        display: table;
+    };
+    let _: Vec<Style> = css!("width: 100%; margin: 8px 1x;");
+}
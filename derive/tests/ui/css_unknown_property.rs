@@ -0,0 +1,8 @@
+use elvis::{prelude::*, style::Style};
+
+fn main() {
+    let _: Vec<Style> = css! {
+        width: 100%;
+        colour: red;
+    };
+}
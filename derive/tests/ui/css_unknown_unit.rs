@@ -0,0 +1,8 @@
+use elvis::{prelude::*, style::Style};
+
+fn main() {
+    let _: Vec<Style> = css! {
+        width: "10pq";
+        line-height: 1.5;
+    };
+}
@@ -0,0 +1,12 @@
+use elvis_core::Node;
+use elvis_derive::IntoNode;
+
+#[derive(IntoNode)]
+struct Widget {
+    #[elvis(child)]
+    head: Node,
+    #[elvis(child)]
+    body: Node,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use elvis::prelude::*;
+
+fn main() {
+    let _: Node = rsx! {
+        <Text text="hello" aria-label="greeting" />
+    };
+}
@@ -0,0 +1,7 @@
+use elvis_derive::IntoNode;
+
+#[derive(IntoNode)]
+#[elvis(tag = 1)]
+struct Widget {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use elvis_derive::IntoNode;
+
+#[derive(IntoNode)]
+enum Widget {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use elvis_derive::IntoNode;
+
+#[derive(IntoNode)]
+#[elvis(id = "main")]
+struct Widget {}
+
+fn main() {}
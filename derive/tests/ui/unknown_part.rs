@@ -0,0 +1,10 @@
+use elvis_core::Node;
+use elvis_derive::IntoNode;
+
+#[derive(IntoNode)]
+struct Widget {
+    #[elvis(body)]
+    body: Node,
+}
+
+fn main() {}
@@ -6,7 +6,7 @@ use elvis::{
     Router,
 };
 
-#[page]
+#[page(route = "/back", title = "Back")]
 struct Back;
 
 impl LifeCycle for Back {
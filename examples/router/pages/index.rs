@@ -6,7 +6,7 @@ use elvis::{
     Router,
 };
 
-#[page]
+#[page(route = "/", title = "Index")]
 struct Index;
 
 impl LifeCycle for Index {
@@ -15,10 +15,12 @@ mod widget;
 struct Index;
 
 pub fn font() -> FontFamily {
-    FontFamily::Derive(vec![FontFamily::Mix(
-        Box::new(FontFamily::Helvetica),
-        Box::new(FontFamily::Neue),
-    )])
+    FontFamily::Derive(vec![
+        FontFamily::Named("Helvetica Neue".into()),
+        FontFamily::Helvetica,
+        FontFamily::Arial,
+        FontFamily::SansSerif,
+    ])
 }
 
 /// A litter style wrapper of our app
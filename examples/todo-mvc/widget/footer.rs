@@ -33,7 +33,10 @@ pub fn button(text: &str) -> Node {
 
 pub fn footer() -> Node {
     Container::with(
-        List::with(vec![button("All"), button("Active"), button("Completed")])
+        List::new()
+            .child(button("All"))
+            .child(button("Active"))
+            .child(button("Completed"))
             .padding(VecUnit(vec![Unit::Px(10.0), Unit::Px(15.0)]))
             .text_align(TextAlign::Center)
             .border_top(
@@ -70,6 +70,7 @@
 //! [roadmap]: https://github.com/elvisjs/elvis/milestones
 
 #![warn(missing_docs)]
+
 // mod bridge;
 mod err;
 
@@ -79,7 +80,9 @@ pub mod traits;
 pub mod widgets;
 
 // re-exports
-pub use elvis_core::{style, value};
+pub use elvis_core::{
+    location, navigate, style, use_router, value, Class, Closure, Registry, Subscription,
+};
 
 // self exports
 pub use crate::err::Error;
@@ -3,8 +3,14 @@
 //! ```
 //! use elvis::prelude::*;
 //! ```
-pub use elvis_core::{LifeCycle, Node};
-pub use elvis_derive::page;
+pub use elvis_core::{
+    derive::Setter, Component, FormState, LifeCycle, Node, Reactive, ScrollController, Severity,
+    Toast, Toasts,
+};
+pub use elvis_derive::{css, page, rsx, Component};
 
 #[cfg(feature = "web")]
-pub use elvis_web::{wasm_bindgen_re_exports::wasm_bindgen, Page};
+pub use elvis_web::{
+    wasm_bindgen_re_exports::{spawn_local, wasm_bindgen},
+    Page,
+};
@@ -1,2 +1,4 @@
 //! Elvis Traits
-pub use elvis_core::{Driver as DriverTrait, Router as RouterTrait, StyleWrapper};
+pub use elvis_core::{
+    ClassName, Driver as DriverTrait, Parent, Router as RouterTrait, StyleWrapper,
+};
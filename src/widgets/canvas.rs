@@ -0,0 +1,88 @@
+use elvis_core::{
+    derive::Setter, value::Unit, Attribute, Closure, Drawing, Gesture, GestureDetector, Node,
+    StateKV, Style,
+};
+use std::sync::Arc;
+
+/// `Canvas` draws a retained `Drawing` in a `canvas` element
+///
+/// Taps put the tap offsets `x` and `y` into state, and the key of the
+/// region hit as `region` if any.
+#[derive(Setter)]
+pub struct Canvas {
+    /// Drawing to draw
+    pub drawing: Drawing,
+    /// Width in px
+    pub width: f64,
+    /// Height in px
+    pub height: f64,
+    /// Accessible label
+    pub label: String,
+    /// Tap handler
+    #[skip]
+    pub on_tap: Option<Closure<StateKV>>,
+}
+
+impl Default for Canvas {
+    fn default() -> Canvas {
+        Canvas {
+            drawing: Drawing::default(),
+            width: 300.0,
+            height: 150.0,
+            label: String::new(),
+            on_tap: None,
+        }
+    }
+}
+
+impl Canvas {
+    /// Shortcut of `Canvas::new().drawing(Drawing)`
+    pub fn with(drawing: Drawing) -> Self {
+        Canvas::new().drawing(drawing)
+    }
+
+    /// Set tap handler
+    pub fn on_tap(mut self, f: impl Fn(StateKV) + 'static) -> Self {
+        self.on_tap = Some(Arc::new(f));
+        self
+    }
+}
+
+impl Into<Node> for Canvas {
+    fn into(self) -> Node {
+        let mut attr = Attribute::new()
+            .tag("canvas")
+            .prop("width", self.width.to_string())
+            .prop("height", self.height.to_string())
+            .prop("data-drawing", self.drawing.to_string());
+        if !self.label.is_empty() {
+            attr = attr.prop("role", "img").prop("aria-label", self.label);
+        }
+
+        let node = Node::default().attr(attr).style(vec![
+            Style::Width(Unit::Px(self.width)),
+            Style::Height(Unit::Px(self.height)),
+        ]);
+
+        match self.on_tap {
+            Some(f) => {
+                let drawing = self.drawing;
+                GestureDetector::new(node)
+                    .register(Gesture::Tap, move |mut s: StateKV| {
+                        let num = |k: &[u8]| {
+                            s.get(k)
+                                .and_then(|v| String::from_utf8_lossy(v).parse::<f64>().ok())
+                                .unwrap_or_default()
+                        };
+                        if let Some(key) = drawing.hit(num(b"x"), num(b"y")) {
+                            s.insert(b"region".to_vec(), key.into_bytes());
+                        }
+
+                        f(s);
+                    })
+                    .into()
+            }
+            None => node,
+        }
+    }
+}
@@ -0,0 +1,442 @@
+//! Form controls
+//!
+//! Controls render semantic html elements, bind them to a `Reactive` cell to
+//! keep it in sync with the value of the element.
+use elvis_core::{
+    derive::Setter, Attribute, Closure, FormState, Gesture, GestureDetector, Node, Reactive,
+    StateKV,
+};
+use std::sync::Arc;
+
+/// The `value` of form control in state
+pub(crate) fn value_of(s: &StateKV) -> String {
+    String::from_utf8(s.get(b"value".as_ref()).cloned().unwrap_or_default()).unwrap_or_default()
+}
+
+/// The `checked` of form control in state
+pub(crate) fn checked_of(s: &StateKV) -> bool {
+    s.get(b"checked".as_ref()).map(|c| c.as_slice()) == Some(b"true".as_ref())
+}
+
+/// Wrap control with label
+pub(crate) fn labeled(label: &str, control: Node) -> Node {
+    if label.is_empty() {
+        return control;
+    }
+
+    Node::default()
+        .children(vec![
+            Node::default()
+                .children(vec![Node::text(label)])
+                .attr(Attribute::new().tag("span")),
+            control,
+        ])
+        .attr(Attribute::new().tag("label"))
+}
+
+/// Write the changes of control into cell
+pub(crate) fn bind<T: 'static>(
+    control: Node,
+    gesture: Gesture,
+    cell: Option<Reactive<T>>,
+    parse: impl Fn(&StateKV) -> Option<T> + 'static,
+) -> Node {
+    match cell {
+        Some(cell) => GestureDetector::new(control)
+            .register(gesture, move |s| {
+                if let Some(v) = parse(&s) {
+                    cell.set(v);
+                }
+            })
+            .into(),
+        None => control,
+    }
+}
+
+/// Form control attributes
+pub(crate) fn control(tag: &str, name: &str, disabled: bool) -> Attribute {
+    let attr = Attribute::new().tag(tag).flag("disabled", disabled);
+    if name.is_empty() {
+        attr
+    } else {
+        attr.prop("name", name)
+    }
+}
+
+/// Form, writes the events of named controls into `FormState`
+///
+/// Controls are matched by `name`, only registered fields are tracked. The
+/// submit handler runs if all validators pass, async validators are awaited
+/// with the `web` feature.
+#[derive(Default, Setter)]
+pub struct Form {
+    /// Form state
+    pub state: FormState,
+    /// Form content
+    #[setter(each = "child")]
+    pub children: Vec<Node>,
+    /// Submit handler
+    #[skip]
+    pub on_submit: Option<Closure<FormState>>,
+}
+
+impl Form {
+    /// Shortcut of `Form::new().state(FormState)`
+    pub fn with(state: FormState) -> Self {
+        Form::new().state(state)
+    }
+
+    /// Set submit handler
+    pub fn on_submit(mut self, f: impl Fn(FormState) + 'static) -> Self {
+        self.on_submit = Some(Arc::new(f));
+        self
+    }
+}
+
+impl Into<Node> for Form {
+    fn into(self) -> Node {
+        let form = Node::default()
+            .children(self.children)
+            .attr(Attribute::new().tag("form").flag("novalidate", true));
+
+        let (change, blur, submit) = (self.state.clone(), self.state.clone(), self.state);
+        let on_change = move |s: StateKV| {
+            let name = String::from_utf8(s.get(b"name".as_ref()).cloned().unwrap_or_default())
+                .unwrap_or_default();
+            if change.get(&name).is_none() {
+                return;
+            }
+
+            match s.get(b"type".as_ref()).map(|t| t.as_slice()) {
+                Some(b"checkbox") => change.set(&name, checked_of(&s).to_string()),
+                Some(b"radio") if !checked_of(&s) => {}
+                _ => change.set(&name, value_of(&s)),
+            }
+        };
+
+        let on_submit = self.on_submit;
+        GestureDetector::new(form)
+            .register(Gesture::Input, on_change.clone())
+            .register(Gesture::Change, on_change)
+            .register(Gesture::Blur, move |s| {
+                let name = String::from_utf8(s.get(b"name".as_ref()).cloned().unwrap_or_default())
+                    .unwrap_or_default();
+                if blur.get(&name).is_some() {
+                    blur.touch(&name);
+                }
+            })
+            .register(Gesture::Submit, move |_| {
+                let f = match &on_submit {
+                    Some(f) => f.clone(),
+                    None => {
+                        submit.validate();
+                        return;
+                    }
+                };
+
+                #[cfg(feature = "web")]
+                {
+                    let state = submit.clone();
+                    elvis_web::wasm_bindgen_re_exports::spawn_local(async move {
+                        if state.validate_async().await {
+                            f(state);
+                        }
+                    });
+                }
+                #[cfg(not(feature = "web"))]
+                {
+                    if submit.validate() {
+                        f(submit.clone());
+                    }
+                }
+            })
+            .into()
+    }
+}
+
+/// Error message of form field, shows if the field is touched
+#[derive(Default, Setter)]
+pub struct FieldError {
+    /// Form state
+    pub state: FormState,
+    /// Field name
+    pub name: String,
+}
+
+impl Into<Node> for FieldError {
+    fn into(self) -> Node {
+        match self.state.error(&self.name) {
+            Some(error) => Node::default()
+                .children(vec![Node::text(&error)])
+                .attr(Attribute::new().tag("small").prop("role", "alert")),
+            None => Node::default(),
+        }
+    }
+}
+
+/// Button, submits the form if `submit`
+#[derive(Default, Setter)]
+pub struct Button {
+    /// Button content
+    pub child: Node,
+    /// Submit the form
+    pub submit: bool,
+    /// Disable button
+    pub disabled: bool,
+}
+
+impl Button {
+    /// Shortcut of `Button::new().child(Node)`
+    pub fn with(child: impl Into<Node>) -> Self {
+        Button::new().child(child)
+    }
+}
+
+impl Into<Node> for Button {
+    fn into(self) -> Node {
+        Node::default().children(vec![self.child]).attr(
+            control("button", "", self.disabled).r#type(if self.submit {
+                "submit"
+            } else {
+                "button"
+            }),
+        )
+    }
+}
+
+/// Checkbox with label
+#[derive(Default, Setter)]
+pub struct Checkbox {
+    /// Label text
+    pub label: String,
+    /// Field name
+    pub name: String,
+    /// Checked if not bound
+    pub checked: bool,
+    /// Bound cell
+    pub bind: Option<Reactive<bool>>,
+    /// Disable checkbox
+    pub disabled: bool,
+}
+
+impl Into<Node> for Checkbox {
+    fn into(self) -> Node {
+        let checked = self.bind.as_ref().map_or(self.checked, |b| b.get());
+        let input = Node::default().attr(
+            control("input", &self.name, self.disabled)
+                .r#type("checkbox")
+                .flag("checked", checked),
+        );
+
+        labeled(
+            &self.label,
+            bind(input, Gesture::Change, self.bind, |s| Some(checked_of(s))),
+        )
+    }
+}
+
+/// On-off switch, a checkbox with the `switch` role
+#[derive(Default, Setter)]
+pub struct Switch {
+    /// Label text
+    pub label: String,
+    /// Field name
+    pub name: String,
+    /// On if not bound
+    pub on: bool,
+    /// Bound cell
+    pub bind: Option<Reactive<bool>>,
+    /// Disable switch
+    pub disabled: bool,
+}
+
+impl Into<Node> for Switch {
+    fn into(self) -> Node {
+        let on = self.bind.as_ref().map_or(self.on, |b| b.get());
+        let input = Node::default().attr(
+            control("input", &self.name, self.disabled)
+                .r#type("checkbox")
+                .prop("role", "switch")
+                .flag("checked", on),
+        );
+
+        labeled(
+            &self.label,
+            bind(input, Gesture::Change, self.bind, |s| Some(checked_of(s))),
+        )
+    }
+}
+
+/// Radio, radios with the same name and cell are a group
+#[derive(Default, Setter)]
+pub struct Radio {
+    /// Label text
+    pub label: String,
+    /// Group name
+    pub name: String,
+    /// Radio value
+    pub value: String,
+    /// Checked if not bound
+    pub checked: bool,
+    /// Bound cell of the group, holds the checked value
+    pub bind: Option<Reactive<String>>,
+    /// Disable radio
+    pub disabled: bool,
+}
+
+impl Into<Node> for Radio {
+    fn into(self) -> Node {
+        let checked = self
+            .bind
+            .as_ref()
+            .map_or(self.checked, |b| b.get() == self.value);
+        let input = Node::default().attr(
+            control("input", &self.name, self.disabled)
+                .r#type("radio")
+                .prop("value", self.value.as_str())
+                .flag("checked", checked),
+        );
+
+        labeled(
+            &self.label,
+            bind(input, Gesture::Change, self.bind, |s| {
+                if checked_of(s) {
+                    Some(value_of(s))
+                } else {
+                    None
+                }
+            }),
+        )
+    }
+}
+
+/// Select one of the options
+#[derive(Default, Setter)]
+pub struct Select {
+    /// Label text
+    pub label: String,
+    /// Field name
+    pub name: String,
+    /// Options
+    #[setter(each = "option")]
+    pub options: Vec<String>,
+    /// Selected value if not bound
+    pub value: String,
+    /// Bound cell
+    pub bind: Option<Reactive<String>>,
+    /// Disable select
+    pub disabled: bool,
+}
+
+impl Into<Node> for Select {
+    fn into(self) -> Node {
+        let selected = self.bind.as_ref().map_or(self.value, |b| b.get());
+        let options = self
+            .options
+            .iter()
+            .map(|o| {
+                Node::default().children(vec![Node::text(o)]).attr(
+                    Attribute::new()
+                        .tag("option")
+                        .prop("value", o.as_str())
+                        .flag("selected", o == &selected),
+                )
+            })
+            .collect::<Vec<Node>>();
+
+        let select =
+            Node::default()
+                .children(options)
+                .attr(control("select", &self.name, self.disabled));
+
+        labeled(
+            &self.label,
+            bind(select, Gesture::Change, self.bind, |s| Some(value_of(s))),
+        )
+    }
+}
+
+/// Range slider, the bound cell updates when the thumb is released
+#[derive(Setter)]
+#[setter(default)]
+pub struct Slider {
+    /// Label text
+    pub label: String,
+    /// Field name
+    pub name: String,
+    /// Value if not bound
+    pub value: f64,
+    /// Minimum value
+    pub min: f64,
+    /// Maximum value
+    #[setter(default = 100.0)]
+    pub max: f64,
+    /// Step
+    #[setter(default = 1.0)]
+    pub step: f64,
+    /// Bound cell
+    pub bind: Option<Reactive<f64>>,
+    /// Disable slider
+    pub disabled: bool,
+}
+
+impl Into<Node> for Slider {
+    fn into(self) -> Node {
+        let value = self.bind.as_ref().map_or(self.value, |b| b.get());
+        let input = Node::default().attr(
+            control("input", &self.name, self.disabled)
+                .r#type("range")
+                .prop("min", self.min.to_string())
+                .prop("max", self.max.to_string())
+                .prop("step", self.step.to_string())
+                .prop("value", value.to_string()),
+        );
+
+        labeled(
+            &self.label,
+            bind(input, Gesture::Change, self.bind, |s| {
+                value_of(s).parse().ok()
+            }),
+        )
+    }
+}
+
+/// Multi-line text input, the bound cell updates on change
+#[derive(Setter)]
+#[setter(default)]
+pub struct TextArea {
+    /// Label text
+    pub label: String,
+    /// Field name
+    pub name: String,
+    /// Value if not bound
+    pub value: String,
+    /// Placeholder
+    pub placeholder: String,
+    /// Visible lines
+    #[setter(default = 3u32)]
+    pub rows: u32,
+    /// Bound cell
+    pub bind: Option<Reactive<String>>,
+    /// Disable textarea
+    pub disabled: bool,
+    /// Read only
+    pub readonly: bool,
+}
+
+impl Into<Node> for TextArea {
+    fn into(self) -> Node {
+        let value = self.bind.as_ref().map_or(self.value, |b| b.get());
+        let textarea = Node::default().children(vec![Node::text(&value)]).attr(
+            control("textarea", &self.name, self.disabled)
+                .prop("rows", self.rows.to_string())
+                .prop("placeholder", self.placeholder.as_str())
+                .flag("readonly", self.readonly),
+        );
+
+        labeled(
+            &self.label,
+            bind(textarea, Gesture::Change, self.bind, |s| Some(value_of(s))),
+        )
+    }
+}
@@ -0,0 +1,56 @@
+use elvis_core::{derive::Setter, sanitize, Node};
+use std::rc::Rc;
+
+/// Html sanitizer
+pub type Sanitizer = Rc<dyn Fn(&str) -> String>;
+
+/// Trusted markup, rendered as html instead of text
+///
+/// The markup is sanitized by default, set `sanitize(false)` only if you
+/// trust it totally.
+#[derive(Setter)]
+#[setter(default)]
+pub struct RawHtml {
+    /// Html markup
+    #[skip]
+    pub html: String,
+    /// Sanitize markup
+    #[setter(default = true)]
+    pub sanitize: bool,
+    /// Custom sanitizer
+    #[skip]
+    pub sanitizer: Option<Sanitizer>,
+}
+
+impl RawHtml {
+    /// Shortcut of `RawHtml::new().html(&str)`
+    pub fn with(html: &str) -> Self {
+        RawHtml::new().html(html)
+    }
+
+    /// Set markup
+    pub fn html(mut self, html: &str) -> Self {
+        self.html = html.into();
+        self
+    }
+
+    /// Sanitize with custom sanitizer
+    pub fn sanitizer(mut self, f: impl Fn(&str) -> String + 'static) -> Self {
+        self.sanitizer = Some(Rc::new(f));
+        self
+    }
+}
+
+impl Into<Node> for RawHtml {
+    fn into(self) -> Node {
+        let mut node = Node::default();
+        node.attr.tag = "raw".into();
+        node.attr.text = match (self.sanitize, &self.sanitizer) {
+            (false, _) => self.html,
+            (true, Some(f)) => f(&self.html),
+            (true, None) => sanitize(&self.html),
+        };
+
+        node
+    }
+}
@@ -0,0 +1,163 @@
+use elvis_core::{
+    derive::Setter,
+    value::{Color, Unit},
+    Attribute, Namespace, Node, Style,
+};
+
+/// Bundled icons, paths of material icons in a 24 x 24 box
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Icons {
+    /// Plus
+    Add,
+    /// Arrow to left
+    ArrowBack,
+    /// Arrow to right
+    ArrowForward,
+    /// Check mark
+    Check,
+    /// Chevron to left
+    ChevronLeft,
+    /// Chevron to right
+    ChevronRight,
+    /// Cross
+    Close,
+    /// Circle with exclamation mark
+    Error,
+    /// Chevron to bottom
+    ExpandMore,
+    /// Chevron to top
+    ExpandLess,
+    /// House
+    Home,
+    /// Circle with `i`
+    Info,
+    /// Three lines
+    Menu,
+    /// Three vertical dots
+    MoreVert,
+    /// Minus
+    Remove,
+    /// Magnifier
+    Search,
+    /// Star
+    Star,
+    /// Triangle with exclamation mark
+    Warning,
+}
+
+impl Icons {
+    /// Svg path data
+    pub fn path(&self) -> &'static str {
+        match self {
+            Icons::Add => "M19 13h-6v6h-2v-6H5v-2h6V5h2v6h6v2z",
+            Icons::ArrowBack => "M20 11H7.83l5.59-5.59L12 4l-8 8 8 8 1.41-1.41L7.83 13H20v-2z",
+            Icons::ArrowForward => "M12 4l-1.41 1.41L16.17 11H4v2h12.17l-5.58 5.59L12 20l8-8z",
+            Icons::Check => "M9 16.17L4.83 12l-1.42 1.41L9 19 21 7l-1.41-1.41z",
+            Icons::ChevronLeft => "M15.41 7.41L14 6l-6 6 6 6 1.41-1.41L10.83 12z",
+            Icons::ChevronRight => "M10 6L8.59 7.41 13.17 12l-4.58 4.59L10 18l6-6z",
+            Icons::Close => {
+                "M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 \
+                 17.59 19 19 17.59 13.41 12z"
+            }
+            Icons::Error => {
+                "M12 2C6.48 2 2 6.48 2 12s4.48 10 10 10 10-4.48 10-10S17.52 2 12 2zm1 \
+                 15h-2v-2h2v2zm0-4h-2V7h2v6z"
+            }
+            Icons::ExpandMore => "M16.59 8.59L12 13.17 7.41 8.59 6 10l6 6 6-6z",
+            Icons::ExpandLess => "M12 8l-6 6 1.41 1.41L12 10.83l4.59 4.58L18 14z",
+            Icons::Home => "M10 20v-6h4v6h5v-8h3L12 3 2 12h3v8z",
+            Icons::Info => {
+                "M12 2C6.48 2 2 6.48 2 12s4.48 10 10 10 10-4.48 10-10S17.52 2 12 2zm1 \
+                 15h-2v-6h2v6zm0-8h-2V7h2v2z"
+            }
+            Icons::Menu => "M3 18h18v-2H3v2zm0-5h18v-2H3v2zm0-7v2h18V6H3z",
+            Icons::MoreVert => {
+                "M12 8c1.1 0 2-.9 2-2s-.9-2-2-2-2 .9-2 2 .9 2 2 2zm0 2c-1.1 0-2 .9-2 2s.9 2 2 2 \
+                 2-.9 2-2-.9-2-2-2zm0 6c-1.1 0-2 .9-2 2s.9 2 2 2 2-.9 2-2-.9-2-2-2z"
+            }
+            Icons::Remove => "M19 13H5v-2h14v2z",
+            Icons::Search => {
+                "M15.5 14h-.79l-.28-.27C15.41 12.59 16 11.11 16 9.5 16 5.91 13.09 3 9.5 3S3 \
+                 5.91 3 9.5 5.91 16 9.5 16c1.61 0 3.09-.59 4.23-1.57l.27.28v.79l5 4.99L20.49 \
+                 19l-4.99-5zm-6 0C7.01 14 5 11.99 5 9.5S7.01 5 9.5 5 14 7.01 14 9.5 11.99 14 \
+                 9.5 14z"
+            }
+            Icons::Star => {
+                "M12 17.27L18.18 21l-1.64-7.03L22 9.24l-7.19-.61L12 2 9.19 8.63 2 9.24l5.46 \
+                 4.73L5.82 21z"
+            }
+            Icons::Warning => "M1 21h22L12 2 1 21zm12-3h-2v-2h2v2zm0-4h-2v-4h2v4z",
+        }
+    }
+}
+
+/// `Icon` renders inline svg path data, fills with the text color if no
+/// color.
+#[derive(Setter)]
+pub struct Icon {
+    /// Svg path data
+    #[skip]
+    pub path: String,
+    /// Box of path coordinates
+    pub view_box: String,
+    /// Fill color
+    pub color: Option<Color>,
+    /// Width and height
+    pub size: Unit,
+    /// Accessible label, hidden from assistive technologies if empty
+    pub label: String,
+}
+
+impl Default for Icon {
+    fn default() -> Icon {
+        Icon {
+            path: String::new(),
+            view_box: "0 0 24 24".into(),
+            color: None,
+            size: Unit::Px(24.0),
+            label: String::new(),
+        }
+    }
+}
+
+impl Icon {
+    /// Shortcut of `Icon::new().icon(Icons)`
+    pub fn with(icon: Icons) -> Self {
+        Icon::new().icon(icon)
+    }
+
+    /// Set svg path data
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Use bundled icon
+    pub fn icon(self, icon: Icons) -> Self {
+        self.path(icon.path())
+    }
+}
+
+impl Into<Node> for Icon {
+    fn into(self) -> Node {
+        let fill = self
+            .color
+            .map_or_else(|| "currentColor".to_string(), |c| c.to_string());
+        let mut attr = Attribute::new()
+            .tag("svg")
+            .namespace(Namespace::Svg)
+            .prop("viewBox", self.view_box)
+            .prop("fill", fill);
+        attr = if self.label.is_empty() {
+            attr.prop("aria-hidden", "true")
+        } else {
+            attr.prop("role", "img").prop("aria-label", self.label)
+        };
+
+        let path = Node::default().attr(Attribute::new().tag("path").prop("d", self.path));
+        Node::default().children(vec![path]).attr(attr).style(vec![
+            Style::Width(self.size),
+            Style::Height(self.size),
+        ])
+    }
+}
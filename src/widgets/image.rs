@@ -1,18 +1,219 @@
-use elvis_core::{derive::Setter, Attribute, Node};
+use elvis_core::{
+    derive::Setter,
+    value::{Display, ObjectFit, Overflow, Position, Unit},
+    Attribute, Closure, Gesture, GestureDetector, Node, Reactive, StateKV, Style,
+};
+use elvis_derive::Component;
+use std::sync::Arc;
 
-/// If you don't want Image playing in background anonymously, just remove its child.
-#[derive(Default, Setter)]
+/// Loading status of image
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImageStatus {
+    /// Loading
+    Loading,
+    /// Loaded
+    Loaded,
+    /// Failed to load
+    Failed,
+}
+
+impl Default for ImageStatus {
+    fn default() -> ImageStatus {
+        ImageStatus::Loading
+    }
+}
+
+impl ImageStatus {
+    /// Status in `data-status`
+    fn as_str(&self) -> &'static str {
+        match self {
+            ImageStatus::Loading => "loading",
+            ImageStatus::Loaded => "loaded",
+            ImageStatus::Failed => "failed",
+        }
+    }
+}
+
+/// `Image` renders an `img` element, shows the placeholder while loading and
+/// the fallback if failed.
+///
+/// The element updates its `data-status` when the image loads, the status is
+/// stored without rendering the image again. With `background`, the image
+/// covers a `div` with its child in front.
+#[derive(Default, Setter, Component)]
 pub struct Image {
     /// Image source
+    #[prop]
     pub src: String,
-    /// Image child
+    /// Alternate text
+    #[prop]
+    pub alt: String,
+    /// Advisory title
+    #[prop]
+    pub title: String,
+    /// Image candidates, `image-2x.png 2x`
+    #[prop]
+    pub srcset: String,
+    /// Image sizes of media conditions
+    #[prop]
+    pub sizes: String,
+    /// Load lazily
+    #[prop]
+    pub lazy: bool,
+    /// Object fit
+    #[prop]
+    pub fit: Option<ObjectFit>,
+    /// Background mode
+    #[prop]
+    pub background: bool,
+    /// Image child, in background mode
+    #[prop]
     pub child: Node,
+    /// Shows while loading
+    #[prop]
+    pub placeholder: Option<Node>,
+    /// Shows if failed
+    #[prop]
+    pub fallback: Option<Node>,
+    /// Load handler
+    pub on_load: Option<Closure<StateKV>>,
+    /// Error handler
+    pub on_error: Option<Closure<StateKV>>,
+    /// Loading status
+    #[state]
+    pub status: Reactive<ImageStatus>,
 }
 
-impl Into<Node> for Image {
-    fn into(self) -> Node {
+impl Image {
+    /// Shortcut of `Image::new().src(&str)`
+    pub fn with(src: &str) -> Self {
+        Image::new().src(src)
+    }
+
+    /// Set load handler
+    pub fn on_load(mut self, f: impl Fn(StateKV) + 'static) -> Self {
+        self.on_load = Some(Arc::new(f));
+        self
+    }
+
+    /// Set error handler
+    pub fn on_error(mut self, f: impl Fn(StateKV) + 'static) -> Self {
+        self.on_error = Some(Arc::new(f));
+        self
+    }
+
+    /// Report status to cell and handler
+    fn report(
+        &self,
+        status: ImageStatus,
+        handler: &Option<Closure<StateKV>>,
+    ) -> impl Fn(StateKV) + 'static {
+        let (cell, handler) = (self.status.clone(), handler.clone());
+        move |s| {
+            // The element updates `data-status` itself
+            cell.update_silent(|c| *c = status);
+
+            if let Some(f) = &handler {
+                f(s);
+            }
+        }
+    }
+
+    /// Wrap placeholder or fallback with its slot
+    fn slot(node: &Node, slot: &str) -> Node {
+        Node::default()
+            .children(vec![node.clone()])
+            .attr(Attribute::new().tag("span").prop(slot, ""))
+            .style(vec![Style::Display(Display::Block)])
+    }
+
+    fn render(&self) -> Node {
+        let mut attr = Attribute::new().tag("img").src(self.src.as_str());
+        for (k, v) in &[
+            ("alt", &self.alt),
+            ("title", &self.title),
+            ("srcset", &self.srcset),
+            ("sizes", &self.sizes),
+        ] {
+            if !v.is_empty() {
+                attr = attr.prop(k, v.as_str());
+            }
+        }
+        if self.lazy {
+            attr = attr.prop("loading", "lazy");
+        }
+        if !self.background {
+            attr = attr.prop("data-image", "");
+        }
+        if self.fallback.is_some() {
+            attr = attr.prop("data-replaced", "");
+        }
+
+        let mut style = vec![Style::Width(Unit::Percent(100.0))];
+        match (self.fit, self.background) {
+            (Some(fit), _) => style.push(Style::ObjectFit(fit)),
+            (None, true) => style.push(Style::ObjectFit(ObjectFit::Cover)),
+            _ => {}
+        }
+        // Image loads above placeholder, or covers the background
+        if self.background || self.placeholder.is_some() {
+            style.append(&mut vec![
+                Style::Position(Position::Absolute),
+                Style::Top(Unit::Px(0.0)),
+                Style::Left(Unit::Px(0.0)),
+                Style::Height(Unit::Percent(100.0)),
+            ]);
+        }
+
+        let img: Node = GestureDetector::new(Node::default().attr(attr))
+            .register(
+                Gesture::Load,
+                self.report(ImageStatus::Loaded, &self.on_load),
+            )
+            .register(
+                Gesture::Error,
+                self.report(ImageStatus::Failed, &self.on_error),
+            )
+            .into();
+
+        let mut children = vec![];
+        if let Some(placeholder) = &self.placeholder {
+            children.push(Image::slot(placeholder, "data-placeholder"));
+        }
+        children.push(img.style(style));
+        if let Some(fallback) = &self.fallback {
+            children.push(Image::slot(fallback, "data-fallback"));
+        }
+
+        // Spans are valid in paragraphs
+        let mut style = vec![
+            Style::Display(Display::Block),
+            Style::Position(Position::Relative),
+        ];
+        if self.background {
+            children.push(
+                Node::default()
+                    .children(vec![self.child.clone()])
+                    .attr(Attribute::new().tag("span"))
+                    .style(vec![
+                        Style::Display(Display::Block),
+                        Style::Position(Position::Relative),
+                    ]),
+            );
+            style.append(&mut vec![
+                Style::Height(Unit::Percent(100.0)),
+                Style::Width(Unit::Percent(100.0)),
+                Style::Overflow(Overflow::Hidden),
+            ]);
+        }
+
         Node::default()
-            .children(vec![self.child.clone()])
-            .attr(Attribute::new().src(self.src))
+            .children(children)
+            .attr(
+                Attribute::new()
+                    .tag("span")
+                    .prop("data-status", self.status.get().as_str()),
+            )
+            .style(style)
     }
 }
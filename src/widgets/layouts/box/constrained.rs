@@ -0,0 +1,101 @@
+use elvis_core::{
+    derive::Setter,
+    option_to_style,
+    value::{Ratio, Unit},
+    Node, Style,
+};
+
+/// `ConstrainedBox` limits the size of its child
+#[derive(Default, Setter)]
+pub struct ConstrainedBox {
+    /// ConstrainedBox child
+    pub child: Node,
+    /// Min width
+    pub min_width: Option<Unit>,
+    /// Min height
+    pub min_height: Option<Unit>,
+    /// Max width
+    pub max_width: Option<Unit>,
+    /// Max height
+    pub max_height: Option<Unit>,
+}
+
+impl ConstrainedBox {
+    /// Shortcut of `ConstrainedBox::new().child(impl Into<Node>)`
+    pub fn with(child: impl Into<Node>) -> ConstrainedBox {
+        ConstrainedBox::new().child(child)
+    }
+}
+
+impl Into<Node> for ConstrainedBox {
+    fn into(self) -> Node {
+        let mut styles: Vec<Style> = vec![];
+        option_to_style! {
+            styles, [
+                (MinWidth, self.min_width),
+                (MinHeight, self.min_height),
+                (MaxWidth, self.max_width),
+                (MaxHeight, self.max_height),
+            ],
+        }
+
+        Node::default().children(vec![self.child]).style(styles)
+    }
+}
+
+/// `AspectRatio` takes the full width and keeps the height by `ratio`
+#[derive(Default, Setter)]
+pub struct AspectRatio {
+    /// AspectRatio child
+    pub child: Node,
+    /// Width to height, `Ratio(16, 9)` or `Ratio::try_from(1.5)?`
+    pub ratio: Ratio,
+}
+
+impl AspectRatio {
+    /// Shortcut of `AspectRatio::new().child(impl Into<Node>)`
+    pub fn with(child: impl Into<Node>) -> AspectRatio {
+        AspectRatio::new().child(child)
+    }
+}
+
+impl Into<Node> for AspectRatio {
+    fn into(self) -> Node {
+        Node::default().children(vec![self.child]).style(vec![
+            Style::Width(Unit::Percent(100.0)),
+            Style::AspectRatio(self.ratio),
+        ])
+    }
+}
+
+/// `FractionallySizedBox` sizes to the fractions of its parent, `0.5` is half
+#[derive(Default, Setter)]
+pub struct FractionallySizedBox {
+    /// FractionallySizedBox child
+    pub child: Node,
+    /// Fraction of the parent width
+    pub width_factor: Option<f64>,
+    /// Fraction of the parent height
+    pub height_factor: Option<f64>,
+}
+
+impl FractionallySizedBox {
+    /// Shortcut of `FractionallySizedBox::new().child(impl Into<Node>)`
+    pub fn with(child: impl Into<Node>) -> FractionallySizedBox {
+        FractionallySizedBox::new().child(child)
+    }
+}
+
+impl Into<Node> for FractionallySizedBox {
+    fn into(self) -> Node {
+        let mut styles: Vec<Style> = vec![];
+        option_to_style! {
+            styles, [
+                (Width, self.width_factor.map(|f| Unit::Percent(f * 100.0))),
+                (Height, self.height_factor.map(|f| Unit::Percent(f * 100.0))),
+            ],
+        }
+
+        Node::default().children(vec![self.child]).style(styles)
+    }
+}
@@ -1,7 +1,17 @@
 //! Basic layout widgets
 
+mod constrained;
 mod container;
+mod padding;
 mod positioned;
 mod sized_box;
+mod stack;
 
-pub use self::{container::Container, positioned::Positioned, sized_box::SizedBox};
+pub use self::{
+    constrained::{AspectRatio, ConstrainedBox, FractionallySizedBox},
+    container::Container,
+    padding::Padding,
+    positioned::Positioned,
+    sized_box::SizedBox,
+    stack::Stack,
+};
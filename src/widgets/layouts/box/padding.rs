@@ -0,0 +1,25 @@
+use elvis_core::{derive::Setter, value::VecUnit, Node, Style};
+
+/// `Padding` insets its child by `padding`
+#[derive(Default, Setter)]
+pub struct Padding {
+    /// Padding child
+    pub child: Node,
+    /// Padding, like `[Unit::Px(8.0), Unit::Px(16.0)]`
+    pub padding: VecUnit,
+}
+
+impl Padding {
+    /// Shortcut of `Padding::new().child(impl Into<Node>)`
+    pub fn with(child: impl Into<Node>) -> Padding {
+        Padding::new().child(child)
+    }
+}
+
+impl Into<Node> for Padding {
+    fn into(self) -> Node {
+        Node::default()
+            .children(vec![self.child])
+            .style(vec![Style::Padding(self.padding)])
+    }
+}
@@ -5,12 +5,12 @@ use elvis_core::{
     Node, Style, StyleWrapper,
 };
 
-/// Positioned Widget
+/// Positioned Widget, absolute in `Stack` if no position
 #[derive(Default, Setter, Wrapper)]
 pub struct Positioned {
     /// box child
     pub child: Node,
-    /// box position
+    /// box position, `Position::Absolute` by default
     pub pos: Option<Position>,
     /// position top
     pub top: Option<Unit>,
@@ -34,7 +34,7 @@ impl Into<Node> for Positioned {
         let mut styles: Vec<Style> = vec![];
         option_to_style! {
             styles, [
-                (Position, self.pos),
+                (Position, self.pos.or(Some(Position::Absolute))),
                 (Top, self.top),
                 (Right, self.right),
                 (Bottom, self.bottom),
@@ -0,0 +1,52 @@
+use elvis_core::{
+    derive::Setter,
+    value::{layouts::Alignment, Position, Unit},
+    Class, Node, Style,
+};
+
+/// `Stack` overlaps its children, the first child sizes the stack, `Positioned`
+/// children are placed by their offsets, and the others are aligned by `align`.
+#[derive(Setter)]
+#[setter(default)]
+pub struct Stack {
+    /// Stack children
+    #[setter(each = "child")]
+    pub children: Vec<Node>,
+    /// Alignment of the children not positioned
+    #[setter(default = Alignment::TopLeft)]
+    pub align: Alignment,
+}
+
+impl Into<Node> for Stack {
+    fn into(self) -> Node {
+        let align = self.align;
+        let children = self
+            .children
+            .into_iter()
+            .enumerate()
+            .map(|(i, child)| {
+                if i == 0 || child.style.iter().any(|s| matches!(s, Style::Position(_))) {
+                    return child;
+                }
+
+                // Layer above the first child
+                let mut style: Vec<Style> = align.into();
+                style.append(&mut vec![
+                    Style::Position(Position::Absolute),
+                    Style::Top(Unit::Px(0.0)),
+                    Style::Left(Unit::Px(0.0)),
+                    Style::Width(Unit::Percent(100.0)),
+                    Style::Height(Unit::Percent(100.0)),
+                ]);
+                Node::default()
+                    .children(vec![child])
+                    .class(vec![Class::Flex])
+                    .style(style)
+            })
+            .collect();
+
+        Node::default()
+            .children(children)
+            .style(vec![Style::Position(Position::Relative)])
+    }
+}
@@ -1,11 +1,12 @@
 //! Elvis column layout
-use elvis_core::{derive::Setter, style::MultiColumnStyle, Class, Node};
+use elvis_core::{derive::Setter, style::MultiColumnStyle, Node};
 use elvis_derive::IntoNode;
 
 /// **Homework**: code a New York Times.
 #[derive(Default, IntoNode, Setter)]
 pub struct MultiColumn {
     /// Column children
+    #[setter(each = "child")]
     pub children: Vec<Node>,
     /// Column style
     pub style: MultiColumnStyle,
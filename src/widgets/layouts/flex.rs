@@ -1,5 +1,13 @@
 //! Flex Layout
-use elvis_core::{derive::Setter, style::FlexStyle, value::layouts::Alignment, Class, Node};
+use elvis_core::{
+    derive::Setter,
+    style::FlexStyle,
+    value::{
+        layouts::{Alignment, FlexBasis, FlexDirection, FlexWrap},
+        Unit,
+    },
+    Class, Node, Style,
+};
 use elvis_derive::IntoNode;
 
 /// `Align` inherits the core usage of Alignment, it's quite simple, just one property.
@@ -45,20 +53,10 @@ impl Into<Node> for Center {
 #[derive(Default, Setter)]
 pub struct Col {
     /// Column children
+    #[setter(each = "child")]
     pub children: Vec<Node>,
 }
 
-impl Col {
-    /// New Col with children
-    pub fn with(children: Vec<impl Into<Node>>) -> Col {
-        let mut col = Col::new();
-        for n in children {
-            col.children.push(n.into());
-        }
-        col
-    }
-}
-
 impl Into<Node> for Col {
     fn into(self) -> Node {
         Node::default()
@@ -69,6 +67,7 @@ impl Into<Node> for Col {
 
 /// This is the Lunatic Widget to Ground Control, 'I`m stepping throw the Window.'
 #[derive(Default, IntoNode, Setter)]
+#[elvis(class = "flex")]
 pub struct Flex {
     /// Flex child
     pub child: Node,
@@ -80,24 +79,166 @@ pub struct Flex {
 #[derive(Default, Setter)]
 pub struct Row {
     /// Row children
+    #[setter(each = "child")]
     pub children: Vec<Node>,
 }
 
-impl Row {
-    /// New Row with children
-    pub fn with(children: Vec<impl Into<Node>>) -> Row {
-        let mut row = Row::new();
-        for n in children {
-            row.children.push(n.into());
+impl Into<Node> for Row {
+    fn into(self) -> Node {
+        Node::default()
+            .children(self.children)
+            .class(vec![Class::Flex, Class::Row])
+    }
+}
+
+/// How `Flexible` fills the space
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FlexFit {
+    /// Fills the space
+    Tight,
+    /// Grows to the space at most
+    Loose,
+}
+
+impl Default for FlexFit {
+    fn default() -> FlexFit {
+        FlexFit::Loose
+    }
+}
+
+/// `Flexible` shares the free space of `Row` or `Col` by its flex factor
+#[derive(Setter)]
+pub struct Flexible {
+    /// Flexible child
+    pub child: Node,
+    /// Flex factor
+    pub flex: i64,
+    /// Flex fit
+    pub fit: FlexFit,
+}
+
+impl Default for Flexible {
+    fn default() -> Flexible {
+        Flexible {
+            child: Node::default(),
+            flex: 1,
+            fit: FlexFit::Loose,
         }
-        row
     }
 }
 
-impl Into<Node> for Row {
+impl Flexible {
+    /// Shortcut of `Flexible::new().child(impl Into<Node>)`
+    pub fn with(child: impl Into<Node>) -> Flexible {
+        Flexible::new().child(child)
+    }
+}
+
+impl Into<Node> for Flexible {
+    fn into(self) -> Node {
+        let basis = match self.fit {
+            FlexFit::Tight => FlexBasis::Number(Unit::Px(0.0)),
+            FlexFit::Loose => FlexBasis::Auto,
+        };
+
+        Node::default().children(vec![self.child]).style(vec![
+            Style::FlexGrow(Unit::None(self.flex as f64)),
+            Style::FlexShrink(Unit::None(1.0)),
+            Style::FlexBasis(basis),
+            Style::MinWidth(Unit::Px(0.0)),
+            Style::MinHeight(Unit::Px(0.0)),
+        ])
+    }
+}
+
+/// `Expanded` fills the free space of `Row` or `Col` by its flex factor
+#[derive(Setter)]
+pub struct Expanded {
+    /// Expanded child
+    pub child: Node,
+    /// Flex factor
+    pub flex: i64,
+}
+
+impl Default for Expanded {
+    fn default() -> Expanded {
+        Expanded {
+            child: Node::default(),
+            flex: 1,
+        }
+    }
+}
+
+impl Expanded {
+    /// Shortcut of `Expanded::new().child(impl Into<Node>)`
+    pub fn with(child: impl Into<Node>) -> Expanded {
+        Expanded::new().child(child)
+    }
+}
+
+impl Into<Node> for Expanded {
     fn into(self) -> Node {
+        Flexible::with(self.child)
+            .flex(self.flex)
+            .fit(FlexFit::Tight)
+            .into()
+    }
+}
+
+/// `Spacer` takes the free space between the children of `Row` or `Col`
+#[derive(Setter)]
+pub struct Spacer {
+    /// Flex factor
+    pub flex: i64,
+}
+
+impl Default for Spacer {
+    fn default() -> Spacer {
+        Spacer { flex: 1 }
+    }
+}
+
+impl Into<Node> for Spacer {
+    fn into(self) -> Node {
+        Expanded::new().flex(self.flex).into()
+    }
+}
+
+/// `Wrap` lays out its children in runs, starts a new run if no space
+#[derive(Default, Setter)]
+pub struct Wrap {
+    /// Wrap children
+    #[setter(each = "child")]
+    pub children: Vec<Node>,
+    /// Direction of runs
+    pub direction: FlexDirection,
+    /// Space between children in a run
+    pub spacing: Unit,
+    /// Space between runs
+    pub run_spacing: Unit,
+}
+
+impl Into<Node> for Wrap {
+    fn into(self) -> Node {
+        let (gap, run_gap) = match self.direction {
+            FlexDirection::Column | FlexDirection::ColumnReverse => (
+                Style::RowGap(self.spacing),
+                Style::ColumnGap(self.run_spacing),
+            ),
+            _ => (
+                Style::ColumnGap(self.spacing),
+                Style::RowGap(self.run_spacing),
+            ),
+        };
+
         Node::default()
             .children(self.children)
-            .class(vec![Class::Flex, Class::Row])
+            .class(vec![Class::Flex])
+            .style(vec![
+                Style::FlexDirection(self.direction),
+                Style::FlexWrap(FlexWrap::Wrap),
+                gap,
+                run_gap,
+            ])
     }
 }
@@ -4,9 +4,14 @@ mod column;
 mod flex;
 mod grid;
 mod list;
+mod r#virtual;
 
 pub use column::MultiColumn;
-pub use flex::{Align, Center, Col, Flex, Row};
+pub use flex::{Align, Center, Col, Expanded, Flex, FlexFit, Flexible, Row, Spacer, Wrap};
 pub use grid::Grid;
 pub use list::List;
-pub use r#box::{Container, Positioned, SizedBox};
+pub use r#box::{
+    AspectRatio, ConstrainedBox, Container, FractionallySizedBox, Padding, Positioned, SizedBox,
+    Stack,
+};
+pub use r#virtual::{Builder, VirtualGrid, VirtualList};
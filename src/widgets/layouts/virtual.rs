@@ -0,0 +1,351 @@
+//! Virtualized layouts
+//!
+//! Virtual layouts only render the items in the visible window plus overscan
+//! between two spacers, only the items render again when the window moves.
+use elvis_core::{
+    derive::Setter,
+    value::{Display, Overflow, Unit},
+    Attribute, Gesture, GestureDetector, Node, Reactive, StateKV, Style,
+};
+use elvis_derive::Component;
+use std::{collections::BTreeMap, ops::Range, rc::Rc};
+
+/// Item builder, creates the node of index
+pub type Builder = Rc<dyn Fn(usize) -> Node>;
+
+/// Scroll offset and the heights of items measured in the DOM
+#[derive(Clone, Default)]
+struct Measured {
+    top: f64,
+    heights: BTreeMap<usize, f64>,
+}
+
+/// Offsets of items in px
+#[derive(Clone)]
+struct Window {
+    count: usize,
+    size: f64,
+    offsets: Option<Rc<Vec<f64>>>,
+    viewport: f64,
+    overscan: usize,
+}
+
+impl Window {
+    /// Window of items, measured items use their heights, the others `size`
+    fn new(
+        count: usize,
+        size: f64,
+        heights: &BTreeMap<usize, f64>,
+        viewport: f64,
+        overscan: usize,
+    ) -> Self {
+        let size = size.max(1.0);
+        let offsets = if heights.is_empty() {
+            None
+        } else {
+            let mut offsets = Vec::with_capacity(count + 1);
+            offsets.push(0.0);
+            for i in 0..count {
+                offsets.push(offsets[i] + heights.get(&i).copied().unwrap_or(size));
+            }
+
+            Some(Rc::new(offsets))
+        };
+
+        Window {
+            count,
+            size,
+            offsets,
+            viewport,
+            overscan,
+        }
+    }
+
+    /// Offset of item
+    fn offset(&self, i: usize) -> f64 {
+        match &self.offsets {
+            Some(offsets) => offsets[i],
+            None => i as f64 * self.size,
+        }
+    }
+
+    /// Index of the item at offset
+    fn index(&self, top: f64) -> usize {
+        match &self.offsets {
+            Some(offsets) => offsets[1..].partition_point(|o| *o <= top),
+            None => (top / self.size).floor().max(0.0) as usize,
+        }
+    }
+
+    /// Items to render at scroll offset
+    fn range(&self, top: f64) -> Range<usize> {
+        let first = self.index(top).min(self.count);
+        let last = (self.index(top + self.viewport) + 1).min(self.count);
+        first.saturating_sub(self.overscan)..(last + self.overscan).min(self.count)
+    }
+
+    /// Spacer before or after the rendered items
+    fn spacer(height: f64) -> Node {
+        Node::default().style(vec![Style::Height(Unit::Px(height.max(0.0)))])
+    }
+}
+
+/// Parse the number of key in state
+fn number(s: &StateKV, key: &[u8]) -> Option<f64> {
+    String::from_utf8(s.get(key)?.clone()).ok()?.parse().ok()
+}
+
+/// Scrollable viewport of virtual views, it stays in the DOM while the items
+/// render again
+fn viewport(
+    height: f64,
+    measured: &Reactive<Measured>,
+    rows: Node,
+    window: impl Fn(&Measured) -> Range<usize> + 'static,
+    range: Reactive<Range<usize>>,
+) -> Node {
+    let container = Node::default()
+        .children(vec![rows])
+        .attr(
+            Attribute::new()
+                .prop("data-virtual", "")
+                .prop("data-scroll-top", measured.with(|m| m.top).to_string()),
+        )
+        .style(vec![
+            Style::Height(Unit::Px(height)),
+            Style::Overflow(Overflow::Auto),
+        ]);
+
+    let measured = measured.clone();
+    GestureDetector::new(container)
+        .register(Gesture::Scroll, move |s| {
+            let next = measured.update_silent(|m| {
+                m.top = number(&s, b"scroll_top").unwrap_or(0.0);
+                let heights = s.get(b"heights".as_ref()).cloned().unwrap_or_default();
+                for item in String::from_utf8(heights).unwrap_or_default().split(',') {
+                    let mut kv = item.split(':');
+                    if let (Some(Ok(i)), Some(Ok(h))) = (
+                        kv.next().map(str::parse::<usize>),
+                        kv.next().map(str::parse::<f64>),
+                    ) {
+                        m.heights.insert(i, h);
+                    }
+                }
+
+                window(m)
+            });
+
+            // Only the items render again if the window moves
+            if next != range.get() {
+                range.set(next);
+            }
+        })
+        .into()
+}
+
+/// Rendered items of virtual views between spacers
+#[derive(Component)]
+struct Rows {
+    window: Rc<dyn Fn(&Measured) -> Window>,
+    measured: Reactive<Measured>,
+    columns: usize,
+    cells: usize,
+    builder: Option<Builder>,
+    #[state]
+    range: Reactive<Range<usize>>,
+}
+
+impl Rows {
+    fn render(&self) -> Node {
+        let window = self.measured.with(|m| (self.window)(m));
+        // restored ranges may outlive a smaller count
+        let rows = self.range.get();
+        let rows = rows.start.min(window.count)..rows.end.min(window.count);
+        let mut children = vec![Window::spacer(window.offset(rows.start))];
+        if let Some(builder) = &self.builder {
+            for row in rows.clone() {
+                let item = if self.columns == 0 {
+                    builder(row)
+                } else {
+                    let cells =
+                        (row * self.columns..((row + 1) * self.columns).min(self.cells))
+                            .map(|i| {
+                                Node::default().children(vec![builder(i)]).style(vec![
+                                    Style::Width(Unit::Percent(100.0 / self.columns as f64)),
+                                ])
+                            })
+                            .collect();
+                    Node::default().children(cells).style(vec![
+                        Style::Display(Display::Flex),
+                        Style::Height(Unit::Px(window.size)),
+                    ])
+                };
+
+                children.push(
+                    Node::default()
+                        .children(vec![item])
+                        .attr(Attribute::new().prop("data-index", row.to_string())),
+                );
+            }
+        }
+        children.push(Window::spacer(
+            window.offset(window.count) - window.offset(rows.end),
+        ));
+
+        Node::default().children(children)
+    }
+}
+
+/// `VirtualList` renders the visible rows of a long list, items are created by
+/// the builder, their heights are measured in the DOM while scrolling.
+#[derive(Setter, Component)]
+#[setter(default)]
+pub struct VirtualList {
+    /// Item count
+    #[prop]
+    pub count: usize,
+    /// Estimated item height in px, used until the item is measured
+    #[prop]
+    #[setter(default = 32.0)]
+    pub item_height: f64,
+    /// Viewport height in px
+    #[prop]
+    #[setter(default = 400.0)]
+    pub height: f64,
+    /// Items rendered beyond the visible window on both sides
+    #[prop]
+    #[setter(default = 3usize)]
+    pub overscan: usize,
+    /// Item builder
+    pub builder: Option<Builder>,
+    /// Scroll offset and measured heights, they don't render again
+    #[state]
+    measured: Reactive<Measured>,
+}
+
+impl VirtualList {
+    /// Shortcut of `VirtualList::new().count(usize).item(Fn)`
+    pub fn with(count: usize, item: impl Fn(usize) -> Node + 'static) -> Self {
+        VirtualList::new().count(count).item(item)
+    }
+
+    /// Set item builder
+    pub fn item(mut self, item: impl Fn(usize) -> Node + 'static) -> Self {
+        self.builder = Some(Rc::new(item));
+        self
+    }
+
+    /// Items to render at scroll offset
+    pub fn range(&self, top: f64) -> Range<usize> {
+        self.measured.with(|m| self.window()(m)).range(top)
+    }
+
+    fn window(&self) -> impl Fn(&Measured) -> Window + 'static {
+        let (count, size, height, overscan) =
+            (self.count, self.item_height, self.height, self.overscan);
+        move |m: &Measured| Window::new(count, size, &m.heights, height, overscan)
+    }
+
+    fn render(&self) -> Node {
+        let window = self.window();
+        let range = Reactive::new(self.measured.with(|m| window(m).range(m.top)));
+        let rows = Rows {
+            window: Rc::new(self.window()),
+            measured: self.measured.clone(),
+            columns: 0,
+            cells: self.count,
+            builder: self.builder.clone(),
+            range: range.clone(),
+        };
+
+        viewport(
+            self.height,
+            &self.measured,
+            rows.into(),
+            move |m| window(m).range(m.top),
+            range,
+        )
+    }
+}
+
+/// `VirtualGrid` renders the visible rows of a long grid, cells are created by
+/// the builder with fixed row heights.
+#[derive(Setter, Component)]
+#[setter(default)]
+pub struct VirtualGrid {
+    /// Cell count
+    #[prop]
+    pub count: usize,
+    /// Column count
+    #[prop]
+    #[setter(default = 4usize)]
+    pub columns: usize,
+    /// Row height in px
+    #[prop]
+    #[setter(default = 32.0)]
+    pub row_height: f64,
+    /// Viewport height in px
+    #[prop]
+    #[setter(default = 400.0)]
+    pub height: f64,
+    /// Rows rendered beyond the visible window on both sides
+    #[prop]
+    #[setter(default = 3usize)]
+    pub overscan: usize,
+    /// Cell builder
+    pub builder: Option<Builder>,
+    /// Scroll offset, it doesn't render again
+    #[state]
+    measured: Reactive<Measured>,
+}
+
+impl VirtualGrid {
+    /// Shortcut of `VirtualGrid::new().count(usize).item(Fn)`
+    pub fn with(count: usize, item: impl Fn(usize) -> Node + 'static) -> Self {
+        VirtualGrid::new().count(count).item(item)
+    }
+
+    /// Set cell builder
+    pub fn item(mut self, item: impl Fn(usize) -> Node + 'static) -> Self {
+        self.builder = Some(Rc::new(item));
+        self
+    }
+
+    /// Cells to render at scroll offset
+    pub fn range(&self, top: f64) -> Range<usize> {
+        let rows = self.window()(&Measured::default()).range(top);
+        (rows.start * self.cols()).min(self.count)..(rows.end * self.cols()).min(self.count)
+    }
+
+    fn cols(&self) -> usize {
+        self.columns.max(1)
+    }
+
+    /// Window of rows, rows have fixed heights
+    fn window(&self) -> impl Fn(&Measured) -> Window + 'static {
+        let rows = (self.count + self.cols() - 1) / self.cols();
+        let (size, height, overscan) = (self.row_height, self.height, self.overscan);
+        move |_: &Measured| Window::new(rows, size, &BTreeMap::new(), height, overscan)
+    }
+
+    fn render(&self) -> Node {
+        let window = self.window();
+        let range = Reactive::new(self.measured.with(|m| window(m).range(m.top)));
+        let rows = Rows {
+            window: Rc::new(self.window()),
+            measured: self.measured.clone(),
+            columns: self.cols(),
+            cells: self.count,
+            builder: self.builder.clone(),
+            range: range.clone(),
+        };
+
+        viewport(
+            self.height,
+            &self.measured,
+            rows.into(),
+            move |m| window(m).range(m.top),
+            range,
+        )
+    }
+}
@@ -0,0 +1,544 @@
+//! Markdown
+//!
+//! Parses CommonMark with tables, strikethrough and task lists into `Node`
+//! trees, each element is created by a `MarkdownRenderer`.
+use crate::widgets::{layouts::List, Image, Link, RawHtml, Text};
+use elvis_core::{derive::Setter, safe_url, value::TextAlign, Attribute, Class, Node, Style};
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag};
+use std::rc::Rc;
+
+/// Node with tag and children
+fn element(tag: &str, children: Vec<Node>) -> Node {
+    Node::default()
+        .children(children)
+        .attr(Attribute::new().tag(tag))
+}
+
+/// Creates nodes of markdown elements, override methods to restyle
+pub trait MarkdownRenderer {
+    /// Whole document
+    fn document(&self, blocks: Vec<Node>) -> Node {
+        List::new().children(blocks).into()
+    }
+
+    /// Heading of level 1 to 6
+    fn heading(&self, level: u32, children: Vec<Node>) -> Node {
+        Text::new()
+            .tag(format!("h{}", level.clamp(1, 6)))
+            .children(children)
+            .into()
+    }
+
+    /// Paragraph
+    fn paragraph(&self, children: Vec<Node>) -> Node {
+        Text::new().children(children).into()
+    }
+
+    /// Text
+    fn text(&self, text: &str) -> Node {
+        Node::text(text)
+    }
+
+    /// Emphasis
+    fn emphasis(&self, children: Vec<Node>) -> Node {
+        element("em", children)
+    }
+
+    /// Strong
+    fn strong(&self, children: Vec<Node>) -> Node {
+        element("strong", children)
+    }
+
+    /// Strikethrough
+    fn strikethrough(&self, children: Vec<Node>) -> Node {
+        element("del", children)
+    }
+
+    /// Inline code
+    fn code(&self, code: &str) -> Node {
+        element("code", vec![Node::text(code)])
+    }
+
+    /// Code block with language, in class `language-<lang>`
+    fn code_block(&self, lang: &str, code: &str) -> Node {
+        let mut code = element("code", vec![Node::text(code)]);
+        let lang = lang
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .collect::<String>();
+        if !lang.is_empty() {
+            code = code.class(vec![Class::Named(format!("language-{}", lang))]);
+        }
+
+        element("pre", vec![code])
+    }
+
+    /// Link
+    fn link(&self, href: &str, title: &str, mut children: Vec<Node>) -> Node {
+        let child = if children.len() == 1 {
+            children.remove(0)
+        } else {
+            element("span", children)
+        };
+
+        let href = if safe_url(href) { href } else { "" };
+        let mut node: Node = Link::with(child).href(href).into();
+        if !title.is_empty() {
+            node.attr.props.insert("title".into(), title.into());
+        }
+        node
+    }
+
+    /// Image
+    fn image(&self, src: &str, alt: &str, title: &str) -> Node {
+        let src = if safe_url(src) { src } else { "" };
+        Image::with(src).alt(alt).title(title).lazy(true).into()
+    }
+
+    /// List, ordered if starts with a number
+    fn list(&self, start: Option<u64>, items: Vec<Node>) -> Node {
+        match start {
+            Some(1) => element("ol", items),
+            Some(start) => element("ol", items)
+                .attr(Attribute::new().tag("ol").prop("start", start.to_string())),
+            None => element("ul", items),
+        }
+    }
+
+    /// List item
+    fn item(&self, children: Vec<Node>) -> Node {
+        element("li", children)
+    }
+
+    /// Task marker of list item
+    fn task(&self, checked: bool) -> Node {
+        Node::default().attr(
+            Attribute::new()
+                .tag("input")
+                .r#type("checkbox")
+                .flag("checked", checked)
+                .flag("disabled", true),
+        )
+    }
+
+    /// Block quote
+    fn quote(&self, children: Vec<Node>) -> Node {
+        element("blockquote", children)
+    }
+
+    /// Horizontal rule
+    fn rule(&self) -> Node {
+        element("hr", vec![])
+    }
+
+    /// Hard line break
+    fn line_break(&self) -> Node {
+        element("br", vec![])
+    }
+
+    /// Html in markdown, sanitized, inline html comes with the text around it,
+    /// and with the inline markdown it wraps
+    fn html(&self, html: &str) -> Node {
+        RawHtml::with(html).into()
+    }
+
+    /// Table with head row and body rows
+    fn table(&self, head: Node, rows: Vec<Node>) -> Node {
+        element(
+            "table",
+            vec![element("thead", vec![head]), element("tbody", rows)],
+        )
+    }
+
+    /// Table row
+    fn table_row(&self, cells: Vec<Node>) -> Node {
+        element("tr", cells)
+    }
+
+    /// Table cell
+    fn table_cell(&self, head: bool, align: Option<TextAlign>, children: Vec<Node>) -> Node {
+        let cell = element(if head { "th" } else { "td" }, children);
+        match align {
+            Some(align) => cell.style(vec![Style::TextAlign(align)]),
+            None => cell,
+        }
+    }
+}
+
+/// Renders markdown elements with the common widgets
+pub struct DefaultRenderer;
+
+impl MarkdownRenderer for DefaultRenderer {}
+
+/// Escape text joining inline html
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Elements without end tags
+const VOID: &[&str] = &["br", "hr", "img", "input", "wbr"];
+
+/// Count of tags opened in html and not closed
+fn open_tags(html: &str) -> usize {
+    let mut open: usize = 0;
+    for tag in html.split('<').skip(1) {
+        let tag = tag.split('>').next().unwrap_or("");
+        if tag.starts_with('/') {
+            open = open.saturating_sub(1);
+        } else if tag.starts_with(|c: char| c.is_ascii_alphabetic()) && !tag.ends_with('/') {
+            let name = tag
+                .split(|c: char| c.is_whitespace())
+                .next()
+                .unwrap_or("")
+                .to_ascii_lowercase();
+            if !VOID.contains(&name.as_str()) {
+                open += 1;
+            }
+        }
+    }
+
+    open
+}
+
+/// Markup of inline markdown inside inline html, counts the elements opened
+fn inline_html(event: &Event, opened: &mut usize) -> Option<String> {
+    match event {
+        Event::Start(tag) => {
+            let markup = match tag {
+                Tag::Emphasis => "<em>".into(),
+                Tag::Strong => "<strong>".into(),
+                Tag::Strikethrough => "<del>".into(),
+                Tag::Link(_, href, title) => {
+                    let href = if safe_url(href) { href } else { "" };
+                    format!("<a href=\"{}\" title=\"{}\">", escape(href), escape(title))
+                }
+                _ => return None,
+            };
+            *opened += 1;
+            Some(markup)
+        }
+        Event::End(tag) if *opened > 0 => {
+            let markup = match tag {
+                Tag::Emphasis => "</em>",
+                Tag::Strong => "</strong>",
+                Tag::Strikethrough => "</del>",
+                Tag::Link(..) => "</a>",
+                _ => return None,
+            };
+            *opened -= 1;
+            Some(markup.into())
+        }
+        Event::Code(code) => Some(format!("<code>{}</code>", escape(code))),
+        Event::HardBreak => Some("<br>".into()),
+        _ => None,
+    }
+}
+
+/// Element in parsing
+struct Frame<'m> {
+    tag: Tag<'m>,
+    children: Vec<Node>,
+    text: String,
+}
+
+/// Table in parsing
+#[derive(Default)]
+struct Table {
+    aligns: Vec<Alignment>,
+    head: Option<Node>,
+    rows: Vec<Node>,
+    cell: usize,
+    in_head: bool,
+}
+
+/// Markdown widget
+#[derive(Setter)]
+pub struct Markdown {
+    /// Markdown source
+    #[skip]
+    pub source: String,
+    /// Element renderer
+    #[skip]
+    pub renderer: Rc<dyn MarkdownRenderer>,
+}
+
+impl Default for Markdown {
+    fn default() -> Markdown {
+        Markdown {
+            source: String::new(),
+            renderer: Rc::new(DefaultRenderer),
+        }
+    }
+}
+
+impl Markdown {
+    /// Shortcut of `Markdown::new().source(&str)`
+    pub fn with(source: &str) -> Self {
+        Markdown::new().source(source)
+    }
+
+    /// Set markdown source
+    pub fn source(mut self, source: &str) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Set element renderer
+    pub fn renderer(mut self, renderer: impl MarkdownRenderer + 'static) -> Self {
+        self.renderer = Rc::new(renderer);
+        self
+    }
+
+    /// Close element
+    fn end(&self, frame: Frame, table: &mut Table) -> Option<Node> {
+        let r = &self.renderer;
+        Some(match frame.tag {
+            Tag::Paragraph => r.paragraph(frame.children),
+            Tag::Heading(level) => r.heading(level, frame.children),
+            Tag::BlockQuote => r.quote(frame.children),
+            Tag::CodeBlock(kind) => {
+                let lang = match kind {
+                    CodeBlockKind::Fenced(lang) => {
+                        lang.split_whitespace().next().unwrap_or("").to_string()
+                    }
+                    CodeBlockKind::Indented => String::new(),
+                };
+                r.code_block(&lang, &frame.text)
+            }
+            Tag::List(start) => r.list(start, frame.children),
+            Tag::Item => r.item(frame.children),
+            Tag::FootnoteDefinition(_) => r.paragraph(frame.children),
+            Tag::Table(_) => {
+                let table = std::mem::take(table);
+                r.table(
+                    table.head.unwrap_or_else(|| r.table_row(vec![])),
+                    table.rows,
+                )
+            }
+            Tag::TableHead => {
+                // Cells may come without a row in head
+                if table.head.is_none() {
+                    table.head = Some(r.table_row(frame.children));
+                }
+                table.in_head = false;
+                table.cell = 0;
+                return None;
+            }
+            Tag::TableRow => {
+                let row = r.table_row(frame.children);
+                table.cell = 0;
+                if table.in_head {
+                    table.head = Some(row);
+                } else {
+                    table.rows.push(row);
+                }
+                return None;
+            }
+            Tag::TableCell => {
+                let align = match table.aligns.get(table.cell) {
+                    Some(Alignment::Left) => Some(TextAlign::Left),
+                    Some(Alignment::Center) => Some(TextAlign::Center),
+                    Some(Alignment::Right) => Some(TextAlign::Right),
+                    _ => None,
+                };
+                table.cell += 1;
+                r.table_cell(table.in_head, align, frame.children)
+            }
+            Tag::Emphasis => r.emphasis(frame.children),
+            Tag::Strong => r.strong(frame.children),
+            Tag::Strikethrough => r.strikethrough(frame.children),
+            Tag::Link(_, href, title) => r.link(&href, &title, frame.children),
+            Tag::Image(_, src, title) => r.image(&src, &frame.text, &title),
+        })
+    }
+}
+
+impl Into<Node> for Markdown {
+    fn into(self) -> Node {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+        let mut stack: Vec<Frame> = vec![];
+        let mut blocks = vec![];
+        let mut table = Table::default();
+        let r = self.renderer.clone();
+
+        // Consecutive html and the text between, tags split into events, the
+        // inline markdown inside unclosed inline tags joins them
+        let mut html: Option<String> = None;
+        let mut opened = 0;
+        for event in Parser::new_ext(&self.source, options) {
+            match (&mut html, &event) {
+                (_, Event::Html(h)) => {
+                    html.get_or_insert_with(String::new).push_str(h);
+                    continue;
+                }
+                (Some(html), Event::Text(text)) if !stack.is_empty() => {
+                    html.push_str(&escape(text));
+                    continue;
+                }
+                (Some(html), Event::SoftBreak) => {
+                    html.push('\n');
+                    continue;
+                }
+                (Some(h), event) if !stack.is_empty() && (opened > 0 || open_tags(h) > 0) => {
+                    if let Some(markup) = inline_html(event, &mut opened) {
+                        h.push_str(&markup);
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            opened = 0;
+            if let Some(html) = html.take() {
+                let node = r.html(&html);
+                match stack.last_mut() {
+                    Some(frame) => frame.children.push(node),
+                    None => blocks.push(node),
+                }
+            }
+
+            let node = match event {
+                Event::Start(tag) => {
+                    match &tag {
+                        Tag::Table(aligns) => table.aligns = aligns.clone(),
+                        Tag::TableHead => table.in_head = true,
+                        _ => {}
+                    }
+                    stack.push(Frame {
+                        tag,
+                        children: vec![],
+                        text: String::new(),
+                    });
+                    continue;
+                }
+                Event::End(_) => match stack.pop() {
+                    Some(frame) => match self.end(frame, &mut table) {
+                        Some(node) => node,
+                        None => continue,
+                    },
+                    None => continue,
+                },
+                Event::Text(text) => {
+                    // Code blocks and image alts take raw text
+                    if let Some(frame) = stack
+                        .iter_mut()
+                        .rev()
+                        .find(|f| matches!(f.tag, Tag::CodeBlock(_) | Tag::Image(..)))
+                    {
+                        frame.text.push_str(&text);
+                        continue;
+                    }
+                    r.text(&text)
+                }
+                Event::Code(code) => r.code(&code),
+                Event::Html(_) => continue,
+                Event::FootnoteReference(label) => r.text(&format!("[{}]", label)),
+                Event::SoftBreak => r.text(" "),
+                Event::HardBreak => r.line_break(),
+                Event::Rule => r.rule(),
+                Event::TaskListMarker(checked) => r.task(checked),
+            };
+
+            match stack.last_mut() {
+                Some(frame) => frame.children.push(node),
+                None => blocks.push(node),
+            }
+        }
+
+        if let Some(html) = html {
+            blocks.push(r.html(&html));
+        }
+
+        r.document(blocks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Markdown;
+    use elvis_core::{value::TextAlign, Class, Node, Style};
+
+    /// Markup of node, with named classes as `.name`
+    fn html(n: &Node) -> String {
+        if n.attr.tag == "plain" || n.attr.tag == "raw" {
+            return n.attr.text.clone();
+        }
+
+        let mut out = format!("<{}", n.attr.tag);
+        for class in n.class.iter() {
+            if let Class::Named(name) = class {
+                out.push_str(&format!(" .{}", name));
+            }
+        }
+        for (k, v) in &[("src", &n.attr.src), ("href", &n.attr.href)] {
+            if !v.is_empty() {
+                out.push_str(&format!(" {}={:?}", k, v));
+            }
+        }
+        for (k, v) in n.attr.props.iter() {
+            out.push_str(&format!(" {}={:?}", k, v));
+        }
+        out.push('>');
+        for child in n.children.iter() {
+            out.push_str(&html(&child.borrow()));
+        }
+        out.push_str(&format!("</{}>", n.attr.tag));
+        out
+    }
+
+    fn markdown(source: &str) -> String {
+        html(&Markdown::with(source).into())
+    }
+
+    #[test]
+    fn headings() {
+        assert!(markdown("# a\n\n###### b").contains("<h1>a</h1><h6>b</h6>"));
+    }
+
+    #[test]
+    fn tables() {
+        let node: Node = Markdown::with("| a | b |\n|:-|-:|\n| 1 | 2 |\n").into();
+        assert!(html(&node).contains(
+            "<table><thead><tr><th>a</th><th>b</th></tr></thead>\
+             <tbody><tr><td>1</td><td>2</td></tr></tbody></table>"
+        ));
+
+        let table = node.children[0].borrow();
+        let row = table.children[1].borrow().children[0].clone();
+        let cell = row.borrow().children[1].clone();
+        assert!(cell.borrow().style == vec![Style::TextAlign(TextAlign::Right)]);
+    }
+
+    #[test]
+    fn code() {
+        let out = markdown("```rust x\nfn a() {}\n```\n\n```c++\n```\n\n`c`");
+        assert!(out.contains("<pre><code .language-rust>fn a() {}\n</code></pre>"));
+        assert!(out.contains("<pre><code .language-c></code></pre>"));
+        assert!(out.contains("<p><code>c</code></p>"));
+        assert!(!out.contains("class="));
+    }
+
+    #[test]
+    fn links() {
+        let out = markdown("[l](http://a \"t\") [x](javascript:alert(1)) ![alt](i.png \"u\")");
+        assert!(out.contains("<a href=\"http://a\" title=\"t\">l</a>"));
+        assert!(out.contains("<a>x</a>"));
+        assert!(out.contains("<img src=\"i.png\" alt=\"alt\""));
+        assert!(out.contains("title=\"u\""));
+    }
+
+    #[test]
+    fn inline_html() {
+        let out = markdown("<span>*a* [l](http://a)</span> b <br> *c*");
+        assert!(out.contains(
+            "<span><em>a</em> <a href=\"http://a\" title=\"\">l</a></span> b <br> <em>c</em>"
+        ));
+
+        // Unclosed tags end with their paragraph
+        let out = markdown("<b>*a*\n\n*c*");
+        assert!(out.contains("<p><b><em>a</em></p><p><em>c</em></p>"));
+    }
+}
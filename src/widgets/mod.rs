@@ -1,15 +1,87 @@
 //! Evlis common widgets
+mod canvas;
+mod form;
+mod html;
+mod icon;
 mod image;
 pub mod layouts;
 mod link;
+#[cfg(feature = "markdown")]
+mod markdown;
+mod nav;
+mod overlay;
 mod scaffold;
+mod scroll;
+mod table;
 mod text;
 mod tile;
+mod toast;
 
+#[cfg(feature = "markdown")]
+pub use markdown::{DefaultRenderer, Markdown, MarkdownRenderer};
 pub use {
-    image::Image,
+    canvas::Canvas,
+    form::{Button, Checkbox, FieldError, Form, Radio, Select, Slider, Switch, TextArea},
+    html::{RawHtml, Sanitizer},
+    icon::{Icon, Icons},
+    image::{Image, ImageStatus},
     link::Link,
+    nav::{
+        AppBar, BottomNavigationBar, Breadcrumbs, FloatingActionButton, NavItem, NavigationRail,
+        TabBar, TabView,
+    },
+    overlay::{Dialog, Drawer, DrawerSide, Modal, Popover, Portal, Tooltip},
     scaffold::Scaffold,
-    text::{Text, TextField},
+    scroll::{ScrollView, SingleChildScrollView},
+    table::{Column, DataTable, SortOrder, TableState},
+    text::{RichText, Text, TextField, TextSpan},
     tile::ListTile,
+    toast::{Snackbar, ToastHost},
 };
+
+use elvis_core::{Node, Parent};
+use layouts::{
+    Align, AspectRatio, Center, Col, ConstrainedBox, Container, Expanded, Flex, Flexible,
+    FractionallySizedBox, Grid, List, MultiColumn, Padding, Positioned, Row, SizedBox, Stack, Wrap,
+};
+
+/// Implement `Parent` for widgets with a single `child`
+macro_rules! parent_child {
+    ($($widget:ident,)*) => {
+        $(
+            impl Parent for $widget {
+                fn with_children(self, mut children: Vec<Node>) -> Self {
+                    if children.len() == 1 {
+                        self.child(children.remove(0))
+                    } else {
+                        self.child(Node::default().children(children))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Implement `Parent` for widgets with `children`
+macro_rules! parent_children {
+    ($($widget:ident,)*) => {
+        $(
+            impl Parent for $widget {
+                fn with_children(self, children: Vec<Node>) -> Self {
+                    self.children(children)
+                }
+            }
+        )*
+    };
+}
+
+parent_child! {
+    Align, AspectRatio, Center, ConstrainedBox, Container, Drawer, Expanded, Flex, Flexible,
+    FloatingActionButton,
+    FractionallySizedBox, Image, Link, Modal, Padding, Portal, Positioned, SingleChildScrollView,
+    SizedBox,
+}
+
+parent_children! {
+    Col, Form, Grid, List, MultiColumn, Row, ScrollView, Stack, Wrap,
+}
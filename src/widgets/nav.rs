@@ -0,0 +1,582 @@
+//! Navigation
+//!
+//! Destinations with a route push it with the router of `use_router`, and
+//! are active while the route matches `path`, which is `location()` by
+//! default. Destinations without route switch the active index in state.
+use crate::widgets::layouts::{Col, Row};
+use elvis_core::{
+    active_route,
+    derive::Setter,
+    location, navigate,
+    value::{layouts::FlexPosition, BorderRadius, BorderStyle, BoxBorder, Color, Unit, VecUnit},
+    Attribute, Gesture, GestureDetector, Node, Reactive, Style,
+};
+use elvis_derive::Component;
+
+/// If node renders nothing
+fn is_empty(node: &Node) -> bool {
+    node.attr.tag.is_empty() && node.children.is_empty()
+}
+
+/// Navigation destination
+#[derive(Clone, Default, Setter)]
+pub struct NavItem {
+    /// Label text
+    pub label: String,
+    /// Icon, shown before or above the label
+    pub icon: Option<Node>,
+    /// Route to link to
+    pub route: String,
+}
+
+impl NavItem {
+    /// Shortcut of `NavItem::new().label(&str)`
+    pub fn with(label: &str) -> Self {
+        NavItem::new().label(label)
+    }
+}
+
+/// Index of the active item, the route matching path goes first
+fn active(items: &[NavItem], path: &Reactive<String>, index: &Reactive<usize>) -> usize {
+    let path = path.get();
+    if !path.is_empty() {
+        if let Some(i) = active_route(items.iter().map(|i| i.route.as_str()), &path) {
+            return i;
+        }
+    }
+
+    index.get()
+}
+
+/// Link pushes its route on tap, opens as a link in new tabs
+fn link(route: &str, attr: Attribute, content: Node) -> Node {
+    let route = route.to_string();
+    GestureDetector::new(
+        content.attr(
+            attr.tag("a")
+                .href(route.as_str())
+                .prop("data-route", route.as_str()),
+        ),
+    )
+    .register(Gesture::Tap, move |_| {
+        navigate(&route).ok();
+    })
+    .into()
+}
+
+/// Destination pushes its route, or sets index on tap
+fn destination(
+    item: &NavItem,
+    idx: usize,
+    selected: bool,
+    index: &Reactive<usize>,
+    attr: Attribute,
+    content: Node,
+) -> Node {
+    let color = Style::Color(if selected { Color::Blue } else { Color::Black });
+    if !item.route.is_empty() {
+        let attr = if selected {
+            attr.prop("aria-current", "page")
+        } else {
+            attr
+        };
+        return link(&item.route, attr, content.append_style(vec![color]));
+    }
+
+    let index = index.clone();
+    GestureDetector::new(
+        content
+            .attr(attr.tag("button").r#type("button"))
+            .append_style(vec![color]),
+    )
+    .register(Gesture::Tap, move |_| index.set(idx))
+    .into()
+}
+
+/// Icon and label in `Col` or `Row`
+fn content(item: &NavItem, vertical: bool) -> Node {
+    let mut children = vec![];
+    if let Some(icon) = &item.icon {
+        children.push(icon.clone());
+    }
+    if !item.label.is_empty() {
+        children.push(
+            Node::default()
+                .children(vec![Node::text(&item.label)])
+                .attr(Attribute::new().tag("span")),
+        );
+    }
+
+    let node: Node = if vertical {
+        Col::new().children(children).into()
+    } else {
+        Row::new().children(children).into()
+    };
+    node.style(vec![Style::AlignItems(FlexPosition::Center)])
+}
+
+/// App bar, with leading widget, title and actions
+#[derive(Setter)]
+pub struct AppBar {
+    /// Leading widget, like a menu button
+    pub leading: Node,
+    /// Title text
+    pub title: String,
+    /// Action widgets at the end
+    #[setter(each = "action")]
+    pub actions: Vec<Node>,
+    /// Background color
+    pub background: Color,
+    /// Foreground color
+    pub color: Color,
+}
+
+impl Default for AppBar {
+    fn default() -> AppBar {
+        AppBar {
+            leading: Node::default(),
+            title: String::new(),
+            actions: vec![],
+            background: Color::Blue,
+            color: Color::White,
+        }
+    }
+}
+
+impl AppBar {
+    /// Shortcut of `AppBar::new().title(&str)`
+    pub fn with(title: &str) -> Self {
+        AppBar::new().title(title)
+    }
+}
+
+impl Into<Node> for AppBar {
+    fn into(self) -> Node {
+        let mut children = vec![];
+        if !is_empty(&self.leading) {
+            children.push(self.leading);
+        }
+        children.push(
+            Node::default()
+                .children(vec![Node::text(&self.title)])
+                .attr(Attribute::new().tag("h1"))
+                .style(vec![
+                    Style::FontSize(Unit::Px(20.0)),
+                    Style::Margin(VecUnit(vec![Unit::Px(0.0), Unit::Px(16.0)])),
+                    Style::FlexGrow(Unit::None(1.0)),
+                ]),
+        );
+        children.extend(self.actions);
+
+        let node: Node = Row::new().children(children).into();
+        node.attr(Attribute::new().tag("header").prop("role", "banner"))
+            .style(vec![
+                Style::AlignItems(FlexPosition::Center),
+                Style::Height(Unit::Px(56.0)),
+                Style::Padding(VecUnit(vec![Unit::Px(0.0), Unit::Px(16.0)])),
+                Style::BackgroundColor(self.background),
+                Style::Color(self.color),
+            ])
+    }
+}
+
+/// Tab list, arrow keys move the active tab and its focus
+fn tab_bar(
+    tabs: &[NavItem],
+    path: &Reactive<String>,
+    label: &str,
+    index: &Reactive<usize>,
+) -> Node {
+    let current = active(tabs, path, index);
+    let children = tabs
+        .iter()
+        .enumerate()
+        .map(|(i, tab)| {
+            let selected = i == current;
+            let mut attr = Attribute::new()
+                .prop("role", "tab")
+                .prop("aria-selected", selected.to_string())
+                .prop("tabindex", if selected { "0" } else { "-1" });
+            if selected {
+                attr = attr.prop("data-focus", "");
+            }
+            let mut node = destination(tab, i, selected, index, attr, content(tab, false));
+            node.style.extend(vec![
+                Style::Padding(VecUnit(vec![Unit::Px(12.0), Unit::Px(16.0)])),
+                Style::BorderBottom(BoxBorder {
+                    width: Unit::Px(2.0),
+                    style: BorderStyle::Solid,
+                    color: if selected {
+                        Color::Blue
+                    } else {
+                        Color::ORGB(0.0, 0, 0, 0)
+                    },
+                }),
+            ]);
+            node
+        })
+        .collect::<Vec<_>>();
+
+    let mut attr = Attribute::new().prop("role", "tablist");
+    if !label.is_empty() {
+        attr = attr.prop("aria-label", label);
+    }
+
+    let (index, routes) = (
+        index.clone(),
+        tabs.iter().map(|t| t.route.clone()).collect::<Vec<_>>(),
+    );
+    let node: Node = Row::new().children(children).into();
+    GestureDetector::new(node.attr(attr))
+        .register(Gesture::Key, move |s| {
+            let len = routes.len();
+            let next = match s.get(b"key".as_ref()).map(|k| k.as_slice()) {
+                Some(b"ArrowRight") if len > 0 => (current + 1) % len,
+                Some(b"ArrowLeft") if len > 0 => (current + len - 1) % len,
+                _ => return,
+            };
+
+            // Routed tabs follow the path
+            if routes[next].is_empty() {
+                index.set(next);
+            } else {
+                navigate(&routes[next]).ok();
+            }
+        })
+        .into()
+}
+
+/// Tab bar, the active index lives in state
+#[derive(Setter, Component)]
+#[setter(default)]
+pub struct TabBar {
+    /// Tabs
+    #[prop]
+    #[setter(each = "tab")]
+    pub tabs: Vec<NavItem>,
+    /// Current path, the tab whose route matches it is active
+    #[state]
+    #[setter(default = location())]
+    pub path: Reactive<String>,
+    /// Accessible label
+    #[prop]
+    pub label: String,
+    /// Active index
+    #[state]
+    pub index: Reactive<usize>,
+}
+
+impl TabBar {
+    /// Share active index, with a `TabView` for example
+    pub fn index(mut self, index: Reactive<usize>) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Follow path instead of `location()`
+    pub fn path(mut self, path: Reactive<String>) -> Self {
+        self.path = path;
+        self
+    }
+}
+
+impl TabBar {
+    fn render(&self) -> Node {
+        tab_bar(&self.tabs, &self.path, &self.label, &self.index)
+    }
+}
+
+/// Tab bar with the page of the active tab
+#[derive(Setter, Component)]
+#[setter(default)]
+pub struct TabView {
+    /// Tabs
+    #[prop]
+    #[setter(each = "tab")]
+    pub tabs: Vec<NavItem>,
+    /// Pages of tabs
+    #[prop]
+    #[setter(each = "page")]
+    pub pages: Vec<Node>,
+    /// Current path, the tab whose route matches it is active
+    #[state]
+    #[setter(default = location())]
+    pub path: Reactive<String>,
+    /// Accessible label
+    #[prop]
+    pub label: String,
+    /// Active index
+    #[state]
+    pub index: Reactive<usize>,
+}
+
+impl TabView {
+    /// Share active index
+    pub fn index(mut self, index: Reactive<usize>) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Follow path instead of `location()`
+    pub fn path(mut self, path: Reactive<String>) -> Self {
+        self.path = path;
+        self
+    }
+}
+
+impl TabView {
+    fn render(&self) -> Node {
+        let current = active(&self.tabs, &self.path, &self.index);
+        let page = self.pages.get(current).cloned().unwrap_or_default();
+
+        Col::new()
+            .child(tab_bar(&self.tabs, &self.path, &self.label, &self.index))
+            .child(
+                Node::default()
+                    .children(vec![page])
+                    .attr(Attribute::new().prop("role", "tabpanel")),
+            )
+            .into()
+    }
+}
+
+/// Bottom navigation bar, the active index lives in state
+#[derive(Setter, Component)]
+#[setter(default)]
+pub struct BottomNavigationBar {
+    /// Destinations
+    #[prop]
+    #[setter(each = "item")]
+    pub items: Vec<NavItem>,
+    /// Current path, the item whose route matches it is active
+    #[state]
+    #[setter(default = location())]
+    pub path: Reactive<String>,
+    /// Active index
+    #[state]
+    pub index: Reactive<usize>,
+}
+
+impl BottomNavigationBar {
+    /// Share active index
+    pub fn index(mut self, index: Reactive<usize>) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Follow path instead of `location()`
+    pub fn path(mut self, path: Reactive<String>) -> Self {
+        self.path = path;
+        self
+    }
+}
+
+impl BottomNavigationBar {
+    fn render(&self) -> Node {
+        let current = active(&self.items, &self.path, &self.index);
+        let children = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let mut node = destination(
+                    item,
+                    i,
+                    i == current,
+                    &self.index,
+                    Attribute::new(),
+                    content(item, true),
+                );
+                node.style.extend(vec![
+                    Style::FlexGrow(Unit::None(1.0)),
+                    Style::JustifyContent(FlexPosition::Center),
+                ]);
+                node
+            })
+            .collect::<Vec<_>>();
+
+        let node: Node = Row::new().children(children).into();
+        node.attr(Attribute::new().tag("nav")).style(vec![
+            Style::Height(Unit::Px(56.0)),
+            Style::Width(Unit::Percent(100.0)),
+            Style::BackgroundColor(Color::White),
+        ])
+    }
+}
+
+/// Navigation rail at the side, the active index lives in state
+#[derive(Setter, Component)]
+#[setter(default)]
+pub struct NavigationRail {
+    /// Leading widget above destinations, like a floating action button
+    #[prop]
+    pub leading: Node,
+    /// Destinations
+    #[prop]
+    #[setter(each = "item")]
+    pub items: Vec<NavItem>,
+    /// Current path, the item whose route matches it is active
+    #[state]
+    #[setter(default = location())]
+    pub path: Reactive<String>,
+    /// Active index
+    #[state]
+    pub index: Reactive<usize>,
+}
+
+impl NavigationRail {
+    /// Share active index
+    pub fn index(mut self, index: Reactive<usize>) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Follow path instead of `location()`
+    pub fn path(mut self, path: Reactive<String>) -> Self {
+        self.path = path;
+        self
+    }
+}
+
+impl NavigationRail {
+    fn render(&self) -> Node {
+        let current = active(&self.items, &self.path, &self.index);
+        let mut children = vec![];
+        if !is_empty(&self.leading) {
+            children.push(self.leading.clone());
+        }
+        children.extend(self.items.iter().enumerate().map(|(i, item)| {
+            let mut node = destination(
+                item,
+                i,
+                i == current,
+                &self.index,
+                Attribute::new(),
+                content(item, true),
+            );
+            node.style
+                .push(Style::Padding(VecUnit(vec![Unit::Px(12.0), Unit::Px(0.0)])));
+            node
+        }));
+
+        let node: Node = Col::new().children(children).into();
+        node.attr(Attribute::new().tag("nav")).style(vec![
+            Style::AlignItems(FlexPosition::Center),
+            Style::Width(Unit::Px(72.0)),
+            Style::Height(Unit::Percent(100.0)),
+            Style::BackgroundColor(Color::White),
+        ])
+    }
+}
+
+/// Breadcrumbs, the last item is the current page
+#[derive(Setter)]
+pub struct Breadcrumbs {
+    /// Items from the root
+    #[setter(each = "item")]
+    pub items: Vec<NavItem>,
+    /// Separator text
+    pub separator: String,
+}
+
+impl Default for Breadcrumbs {
+    fn default() -> Breadcrumbs {
+        Breadcrumbs {
+            items: vec![],
+            separator: "/".into(),
+        }
+    }
+}
+
+impl Breadcrumbs {
+    /// Items of the segments of path, with `Home` as root, the current page
+    /// is `Breadcrumbs::from_path(&location().get())`
+    pub fn from_path(path: &str) -> Self {
+        let path = elvis_core::route(path);
+        let mut route = String::new();
+        let mut crumbs = Breadcrumbs::new().item(NavItem::with("Home").route("/"));
+        for segment in path.split('/').filter(|s| !s.is_empty()) {
+            route = format!("{}/{}", route, segment);
+            crumbs = crumbs.item(NavItem::with(segment).route(route.as_str()));
+        }
+
+        crumbs
+    }
+}
+
+impl Into<Node> for Breadcrumbs {
+    fn into(self) -> Node {
+        let last = self.items.len().saturating_sub(1);
+        let mut children = vec![];
+        for (i, item) in self.items.iter().enumerate() {
+            let mut nodes = vec![];
+            if i > 0 {
+                nodes.push(
+                    Node::default()
+                        .children(vec![Node::text(&self.separator)])
+                        .attr(Attribute::new().tag("span").prop("aria-hidden", "true"))
+                        .style(vec![Style::Margin(VecUnit(vec![
+                            Unit::Px(0.0),
+                            Unit::Px(8.0),
+                        ]))]),
+                );
+            }
+            nodes.push(if i == last || item.route.is_empty() {
+                let mut attr = Attribute::new().tag("span");
+                if i == last {
+                    attr = attr.prop("aria-current", "page");
+                }
+                content(item, false).attr(attr)
+            } else {
+                link(&item.route, Attribute::new(), content(item, false))
+            });
+
+            let node: Node = Row::new().children(nodes).into();
+            children.push(node.attr(Attribute::new().tag("li")));
+        }
+
+        let list: Node = Row::new().children(children).into();
+        Node::default()
+            .children(vec![list.attr(Attribute::new().tag("ol")).style(vec![
+                Style::Margin(VecUnit(vec![Unit::Px(0.0)])),
+                Style::Padding(VecUnit(vec![Unit::Px(0.0)])),
+            ])])
+            .attr(Attribute::new().tag("nav").prop("aria-label", "Breadcrumb"))
+    }
+}
+
+/// Floating action button
+#[derive(Default, Setter)]
+pub struct FloatingActionButton {
+    /// Button content, usually an icon
+    pub child: Node,
+    /// Accessible label
+    pub label: String,
+}
+
+impl FloatingActionButton {
+    /// Shortcut of `FloatingActionButton::new().child(Node)`
+    pub fn with(child: impl Into<Node>) -> Self {
+        FloatingActionButton::new().child(child)
+    }
+}
+
+impl Into<Node> for FloatingActionButton {
+    fn into(self) -> Node {
+        let mut attr = Attribute::new().tag("button").r#type("button");
+        if !self.label.is_empty() {
+            attr = attr.prop("aria-label", self.label);
+        }
+
+        Node::default()
+            .children(vec![self.child])
+            .attr(attr)
+            .style(vec![
+                Style::Width(Unit::Px(56.0)),
+                Style::Height(Unit::Px(56.0)),
+                Style::BorderRadius(BorderRadius::default().all(Unit::Px(16.0))),
+                Style::BackgroundColor(Color::Blue),
+                Style::Color(Color::White),
+            ])
+    }
+}
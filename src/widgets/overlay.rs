@@ -0,0 +1,450 @@
+//! Overlays
+//!
+//! Overlays render into the overlay root through portals, so they won't be
+//! clipped by their parents.
+use crate::widgets::{
+    layouts::{Col, Row},
+    Text,
+};
+use elvis_core::{
+    derive::Setter,
+    value::{Color, Position, Unit},
+    Attribute, Class, Gesture, GestureDetector, Node, Placement, Reactive, StateKV, Style,
+};
+use elvis_derive::Component;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Keys of anchors
+static ANCHOR: AtomicUsize = AtomicUsize::new(0);
+
+/// If the key in state is `Escape`
+fn escape(s: &StateKV) -> bool {
+    s.get(b"key".as_ref()).map(|k| k.as_slice()) == Some(b"Escape".as_ref())
+}
+
+/// Close on Escape
+fn closable(node: Node, open: &Reactive<bool>) -> Node {
+    let open = open.clone();
+    GestureDetector::new(node)
+        .register(Gesture::Key, move |s| {
+            if escape(&s) && open.get() {
+                open.set(false);
+            }
+        })
+        .into()
+}
+
+/// Renders child into the overlay root, or the element of `target`
+#[derive(Default, Setter)]
+pub struct Portal {
+    /// Portal child
+    pub child: Node,
+    /// Selector of the target element
+    pub target: String,
+}
+
+impl Portal {
+    /// Shortcut of `Portal::new().child(Node)`
+    pub fn with(child: impl Into<Node>) -> Self {
+        Portal::new().child(child)
+    }
+}
+
+impl Into<Node> for Portal {
+    fn into(self) -> Node {
+        let mut attr = Attribute::new().tag("portal");
+        if !self.target.is_empty() {
+            attr = attr.prop("target", self.target);
+        }
+
+        Node::default().children(vec![self.child]).attr(attr)
+    }
+}
+
+/// Full screen layer with backdrop, traps the focus in panel
+fn layer(open: &Reactive<bool>, dismissible: bool, label: &str, panel: Node) -> Node {
+    let fill = vec![
+        Style::Position(Position::Fixed),
+        Style::Top(Unit::Px(0.0)),
+        Style::Left(Unit::Px(0.0)),
+        Style::Width(Unit::Vw(100.0)),
+        Style::Height(Unit::Vh(100.0)),
+    ];
+
+    let mut backdrop = Node::default().style(fill.clone());
+    backdrop
+        .style
+        .push(Style::BackgroundColor(Color::ORGB(0.5, 0, 0, 0)));
+    if dismissible {
+        let open = open.clone();
+        backdrop = GestureDetector::new(backdrop)
+            .register(Gesture::Tap, move |_| open.set(false))
+            .into();
+    }
+
+    let mut attr = Attribute::new()
+        .prop("role", "dialog")
+        .prop("aria-modal", "true")
+        .prop("tabindex", "-1")
+        .prop("data-trap", "");
+    if !label.is_empty() {
+        attr = attr.prop("aria-label", label);
+    }
+    let panel = panel.attr(attr);
+    let panel = if dismissible {
+        closable(panel, open)
+    } else {
+        panel
+    };
+
+    Portal::with(
+        Node::default()
+            .children(vec![backdrop, panel])
+            .class(vec![Class::Flex, Class::Center])
+            .style(fill),
+    )
+    .into()
+}
+
+/// Panel of modal above a backdrop if open
+fn modal(open: &Reactive<bool>, dismissible: bool, label: &str, child: Node) -> Node {
+    if !open.get() {
+        return Node::default();
+    }
+
+    layer(
+        open,
+        dismissible,
+        label,
+        Node::default().children(vec![child]).style(vec![
+            Style::Position(Position::Relative),
+            Style::BackgroundColor(Color::White),
+        ]),
+    )
+}
+
+/// Modal, shows the child above a backdrop if open
+#[derive(Setter, Component)]
+#[setter(default)]
+pub struct Modal {
+    /// Modal child
+    #[prop]
+    pub child: Node,
+    /// Open state
+    #[state]
+    pub open: Reactive<bool>,
+    /// Close on backdrop tap and Escape
+    #[prop]
+    #[setter(default = true)]
+    pub dismissible: bool,
+    /// Accessible label
+    #[prop]
+    pub label: String,
+}
+
+impl Modal {
+    /// Shortcut of `Modal::new().child(Node)`
+    pub fn with(child: impl Into<Node>) -> Self {
+        Modal::new().child(child)
+    }
+
+    /// Share open state, the modal renders again when it changes
+    pub fn open(mut self, open: Reactive<bool>) -> Self {
+        self.open = open;
+        self
+    }
+}
+
+impl Modal {
+    fn render(&self) -> Node {
+        modal(
+            &self.open,
+            self.dismissible,
+            &self.label,
+            self.child.clone(),
+        )
+    }
+}
+
+/// Dialog, a modal with title, content and actions
+#[derive(Setter, Component)]
+#[setter(default)]
+pub struct Dialog {
+    /// Dialog title
+    #[prop]
+    pub title: String,
+    /// Dialog content
+    #[prop]
+    pub child: Node,
+    /// Dialog actions
+    #[prop]
+    #[setter(each = "action")]
+    pub actions: Vec<Node>,
+    /// Open state
+    #[state]
+    pub open: Reactive<bool>,
+    /// Close on backdrop tap and Escape
+    #[prop]
+    #[setter(default = true)]
+    pub dismissible: bool,
+}
+
+impl Dialog {
+    /// Share open state, the dialog renders again when it changes
+    pub fn open(mut self, open: Reactive<bool>) -> Self {
+        self.open = open;
+        self
+    }
+}
+
+impl Dialog {
+    fn render(&self) -> Node {
+        modal(
+            &self.open,
+            self.dismissible,
+            &self.title,
+            Col::new()
+                .child(Text::with(&self.title).tag("h2"))
+                .child(self.child.clone())
+                .child(Row::new().children(self.actions.clone()))
+                .into(),
+        )
+    }
+}
+
+/// Side of drawer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DrawerSide {
+    /// Left side
+    Left,
+    /// Right side
+    Right,
+}
+
+impl Default for DrawerSide {
+    fn default() -> DrawerSide {
+        DrawerSide::Left
+    }
+}
+
+/// Drawer, a modal panel slides from side
+#[derive(Setter, Component)]
+#[setter(default)]
+pub struct Drawer {
+    /// Drawer child
+    #[prop]
+    pub child: Node,
+    /// Open state
+    #[state]
+    pub open: Reactive<bool>,
+    /// Drawer side
+    #[prop]
+    pub side: DrawerSide,
+    /// Drawer width
+    #[prop]
+    #[setter(default = Unit::Px(280.0))]
+    pub width: Unit,
+    /// Close on backdrop tap and Escape
+    #[prop]
+    #[setter(default = true)]
+    pub dismissible: bool,
+    /// Accessible label
+    #[prop]
+    pub label: String,
+}
+
+impl Drawer {
+    /// Shortcut of `Drawer::new().child(Node)`
+    pub fn with(child: impl Into<Node>) -> Self {
+        Drawer::new().child(child)
+    }
+
+    /// Share open state, the drawer renders again when it changes
+    pub fn open(mut self, open: Reactive<bool>) -> Self {
+        self.open = open;
+        self
+    }
+}
+
+impl Drawer {
+    fn render(&self) -> Node {
+        if !self.open.get() {
+            return Node::default();
+        }
+
+        let side = match self.side {
+            DrawerSide::Left => Style::Left(Unit::Px(0.0)),
+            DrawerSide::Right => Style::Right(Unit::Px(0.0)),
+        };
+
+        let panel = Node::default()
+            .children(vec![self.child.clone()])
+            .style(vec![
+                Style::Position(Position::Fixed),
+                Style::Top(Unit::Px(0.0)),
+                Style::Bottom(Unit::Px(0.0)),
+                side,
+                Style::Width(self.width),
+                Style::BackgroundColor(Color::White),
+            ]);
+
+        layer(&self.open, self.dismissible, &self.label, panel)
+    }
+}
+
+/// Floating element at the side of anchor
+fn floating(key: &str, placement: Placement, attr: Attribute, child: Node) -> Node {
+    Portal::with(
+        Node::default().children(vec![child]).attr(
+            attr.prop("data-for", key)
+                .prop("data-placement", placement.to_string()),
+        ),
+    )
+    .into()
+}
+
+/// Tooltip, shows text next to child on hover
+#[derive(Default, Setter, Component)]
+pub struct Tooltip {
+    /// Anchor
+    #[prop]
+    pub child: Node,
+    /// Tooltip text
+    #[prop]
+    pub text: String,
+    /// Preferred side, flips if no space
+    #[prop]
+    pub placement: Placement,
+    /// Open state, only the text renders again when it changes
+    pub open: Reactive<bool>,
+}
+
+impl Tooltip {
+    /// Shortcut of `Tooltip::new().child(Node).text(&str)`
+    pub fn with(child: impl Into<Node>, text: &str) -> Self {
+        Tooltip::new().child(child).text(text)
+    }
+}
+
+impl Tooltip {
+    fn render(&self) -> Node {
+        let key = format!("t{}", ANCHOR.fetch_add(1, Ordering::Relaxed));
+        let tip = Tip {
+            key: key.clone(),
+            text: self.text.clone(),
+            placement: self.placement,
+            open: self.open.clone(),
+        };
+
+        // The anchor stays while the text shows and hides
+        let anchor = Node::default()
+            .children(vec![self.child.clone(), tip.into()])
+            .attr(
+                Attribute::new()
+                    .tag("span")
+                    .prop("tabindex", "0")
+                    .prop("data-anchor", key),
+            );
+        let (enter, leave) = (self.open.clone(), self.open.clone());
+        closable(
+            GestureDetector::new(anchor)
+                .register(Gesture::Enter, move |_| {
+                    if !enter.get() {
+                        enter.set(true);
+                    }
+                })
+                .register(Gesture::Leave, move |_| {
+                    if leave.get() {
+                        leave.set(false);
+                    }
+                })
+                .into(),
+            &self.open,
+        )
+    }
+}
+
+/// Text of tooltip, renders into the overlay root if open
+#[derive(Component)]
+struct Tip {
+    key: String,
+    text: String,
+    placement: Placement,
+    #[state]
+    open: Reactive<bool>,
+}
+
+impl Tip {
+    fn render(&self) -> Node {
+        let mut children = vec![];
+        if self.open.get() {
+            children.push(floating(
+                &self.key,
+                self.placement,
+                Attribute::new().prop("role", "tooltip"),
+                Node::text(&self.text),
+            ));
+        }
+
+        Node::default()
+            .children(children)
+            .attr(Attribute::new().tag("span"))
+    }
+}
+
+/// Popover, toggles content next to child on tap
+#[derive(Setter, Component)]
+#[setter(default)]
+pub struct Popover {
+    /// Anchor
+    #[prop]
+    pub child: Node,
+    /// Popover content
+    #[prop]
+    pub content: Node,
+    /// Preferred side, flips if no space
+    #[prop]
+    #[setter(default = Placement::Bottom)]
+    pub placement: Placement,
+    /// Open state
+    #[state]
+    pub open: Reactive<bool>,
+}
+
+impl Popover {
+    /// Shortcut of `Popover::new().child(Node).content(Node)`
+    pub fn with(child: impl Into<Node>, content: impl Into<Node>) -> Self {
+        Popover::new().child(child).content(content)
+    }
+}
+
+impl Popover {
+    fn render(&self) -> Node {
+        let key = format!("p{}", ANCHOR.fetch_add(1, Ordering::Relaxed));
+        let anchor = Node::default().children(vec![self.child.clone()]).attr(
+            Attribute::new()
+                .tag("span")
+                .prop("data-anchor", key.as_str()),
+        );
+
+        let open = self.open.clone();
+        let mut children = vec![GestureDetector::new(anchor)
+            .register(Gesture::Tap, move |_| open.update(|o| *o = !*o))
+            .into()];
+        if self.open.get() {
+            children.push(floating(
+                &key,
+                self.placement,
+                Attribute::new().prop("role", "dialog"),
+                closable(self.content.clone(), &self.open),
+            ));
+        }
+
+        closable(
+            Node::default()
+                .children(children)
+                .attr(Attribute::new().tag("span")),
+            &self.open,
+        )
+    }
+}
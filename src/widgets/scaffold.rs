@@ -1,6 +1,11 @@
-use elvis_core::{derive::Setter, value::Unit, Node, Style};
+use crate::widgets::ToastHost;
+use elvis_core::{
+    derive::Setter,
+    value::{Position, Unit},
+    Node, Style,
+};
 
-/// App Scaffold
+/// App Scaffold, mounts the toast host unless a nested scaffold does
 #[derive(Default, Setter)]
 pub struct Scaffold {
     /// App Header
@@ -9,6 +14,18 @@ pub struct Scaffold {
     pub body: Node,
     /// App footer
     pub footer: Node,
+    /// App drawer, like a `Drawer` opened by the leading of `AppBar`
+    pub drawer: Node,
+    /// Floating action button at the bottom right
+    pub floating_action_button: Node,
+}
+
+/// If the tree of node mounts a toast host
+fn has_host(node: &Node) -> bool {
+    node.component
+        .as_ref()
+        .map_or(false, |c| c.as_any().is::<ToastHost>())
+        || node.children.iter().any(|c| has_host(&c.borrow()))
 }
 
 impl Into<Node> for Scaffold {
@@ -21,6 +38,24 @@ impl Into<Node> for Scaffold {
                 nodes.push(node);
             }
         }
+        if !self.floating_action_button.children.is_empty() {
+            nodes.push(
+                Node::default()
+                    .children(vec![self.floating_action_button])
+                    .style(vec![
+                        Style::Position(Position::Fixed),
+                        Style::Right(Unit::Px(16.0)),
+                        Style::Bottom(Unit::Px(16.0)),
+                    ]),
+            );
+        }
+        if !self.drawer.children.is_empty() {
+            nodes.push(self.drawer);
+        }
+        if !nodes.iter().any(has_host) {
+            nodes.push(ToastHost::new().into());
+        }
+
         Node::default().children(nodes).style(vec![
             Style::Height(Unit::Percent(100.0)),
             Style::Width(Unit::Percent(100.0)),
@@ -0,0 +1,134 @@
+use crate::widgets::layouts::{Col, Row};
+use elvis_core::{
+    derive::Setter,
+    value::{Overflow, Unit},
+    Attribute, Axis, Gesture, GestureDetector, Node, ScrollController, ScrollPosition,
+    ScrollTarget, StateKV, Style,
+};
+use elvis_derive::Component;
+
+/// Scroll position from the state of scroll events
+fn position(s: &StateKV) -> ScrollPosition {
+    let num = |k: &str| {
+        s.get(k.as_bytes())
+            .and_then(|v| String::from_utf8_lossy(v).parse::<f64>().ok())
+            .unwrap_or_default()
+    };
+
+    ScrollPosition {
+        top: num("scroll_top"),
+        left: num("scroll_left"),
+        height: num("scroll_height"),
+        width: num("scroll_width"),
+        client_height: num("client_height"),
+        client_width: num("client_width"),
+    }
+}
+
+/// `SingleChildScrollView` scrolls its child in axis, fills the parent by
+/// default.
+#[derive(Default, Setter, Component)]
+pub struct SingleChildScrollView {
+    /// Scroll child
+    #[prop]
+    pub child: Node,
+    /// Scroll axis
+    #[prop]
+    pub axis: Axis,
+    /// Viewport height, `100%` if none
+    #[prop]
+    pub height: Option<Unit>,
+    /// Viewport width, `100%` if none
+    #[prop]
+    pub width: Option<Unit>,
+    /// Scroll controller
+    #[state]
+    pub controller: ScrollController,
+}
+
+impl SingleChildScrollView {
+    /// Shortcut of `SingleChildScrollView::new().child(Node)`
+    pub fn with(child: impl Into<Node>) -> Self {
+        SingleChildScrollView::new().child(child)
+    }
+
+    /// Set scroll controller
+    pub fn controller(mut self, controller: ScrollController) -> Self {
+        self.controller = controller;
+        self
+    }
+}
+
+impl SingleChildScrollView {
+    fn render(&self) -> Node {
+        let (x, y) = match self.axis {
+            Axis::Vertical => (Overflow::Hidden, Overflow::Auto),
+            Axis::Horizontal => (Overflow::Auto, Overflow::Hidden),
+            Axis::Both => (Overflow::Auto, Overflow::Auto),
+        };
+
+        // Offsets to restore, then the pending request
+        let pos = self.controller.position();
+        let mut attr = Attribute::new()
+            .prop("data-scroll-top", pos.top.to_string())
+            .prop("data-scroll-left", pos.left.to_string());
+        if let Some((target, smooth)) = self.controller.take_request() {
+            attr = match target {
+                ScrollTarget::Offset(top, left) => {
+                    attr.prop("data-scroll-to", format!("{},{}", top, left))
+                }
+                ScrollTarget::Key(key) => attr.prop("data-scroll-into", key),
+            }
+            .flag("data-scroll-smooth", smooth);
+        }
+
+        let view = Node::default()
+            .children(vec![self.child.clone()])
+            .attr(attr)
+            .style(vec![
+                Style::Height(self.height.unwrap_or(Unit::Percent(100.0))),
+                Style::Width(self.width.unwrap_or(Unit::Percent(100.0))),
+                Style::OverflowX(x),
+                Style::OverflowY(y),
+            ]);
+
+        let (controller, axis) = (self.controller.clone(), self.axis);
+        GestureDetector::new(view)
+            .register(Gesture::Scroll, move |s| {
+                controller.scrolled(position(&s), axis)
+            })
+            .into()
+    }
+}
+
+/// `ScrollView` scrolls its children, laid out in a column or a row by axis
+#[derive(Default, Setter)]
+pub struct ScrollView {
+    /// Scroll children
+    #[setter(each = "child")]
+    pub children: Vec<Node>,
+    /// Scroll axis
+    pub axis: Axis,
+    /// Viewport height, `100%` if none
+    pub height: Option<Unit>,
+    /// Viewport width, `100%` if none
+    pub width: Option<Unit>,
+    /// Scroll controller
+    pub controller: ScrollController,
+}
+
+impl Into<Node> for ScrollView {
+    fn into(self) -> Node {
+        let child: Node = match self.axis {
+            Axis::Horizontal => Row::new().children(self.children).into(),
+            _ => Col::new().children(self.children).into(),
+        };
+
+        let mut view = SingleChildScrollView::with(child)
+            .axis(self.axis)
+            .controller(self.controller);
+        view.height = self.height;
+        view.width = self.width;
+        view.into()
+    }
+}
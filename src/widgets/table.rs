@@ -0,0 +1,524 @@
+//! Data table
+//!
+//! `DataTable` renders typed rows into semantic `table` markup, column widths
+//! are set in its `colgroup`.
+use crate::widgets::{form, Button, Text};
+use elvis_core::{
+    style::Border,
+    value::{Color, Overflow, Position, Unit},
+    Attribute, Closure, Component, Gesture, GestureDetector, LifeCycle, Node, Reactive, Style,
+    Subscription,
+};
+use std::{any::Any, cmp::Ordering, collections::BTreeSet, rc::Rc};
+
+/// Column of `DataTable`
+pub struct Column<T> {
+    title: String,
+    cell: Rc<dyn Fn(&T) -> Node>,
+    sort: Option<Rc<dyn Fn(&T, &T) -> Ordering>>,
+    width: Option<Unit>,
+    resizable: bool,
+    sticky: bool,
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Column {
+            title: self.title.clone(),
+            cell: self.cell.clone(),
+            sort: self.sort.clone(),
+            width: self.width,
+            resizable: self.resizable,
+            sticky: self.sticky,
+        }
+    }
+}
+
+impl<T> Column<T> {
+    /// New column with title and cell builder
+    pub fn new(title: &str, cell: impl Fn(&T) -> Node + 'static) -> Self {
+        Column {
+            title: title.into(),
+            cell: Rc::new(cell),
+            sort: None,
+            width: None,
+            resizable: false,
+            sticky: false,
+        }
+    }
+
+    /// Sort rows with comparator
+    pub fn sort_by(mut self, f: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.sort = Some(Rc::new(f));
+        self
+    }
+
+    /// Sort rows with key
+    pub fn sort_key<K: Ord>(self, f: impl Fn(&T) -> K + 'static) -> Self {
+        self.sort_by(move |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Set column width, the columns without widths share the rest
+    pub fn width(mut self, width: Unit) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Resize column by dragging the corner of header
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Stick column at left while scrolling, the sticky columns before it
+    /// need widths in absolute units, relative widths of one unit only stack
+    /// in tables without selection, whose checkboxes are 40px wide
+    pub fn sticky(mut self, sticky: bool) -> Self {
+        self.sticky = sticky;
+        self
+    }
+}
+
+/// Sort direction
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortOrder {
+    /// Ascending
+    Asc,
+    /// Descending
+    Desc,
+}
+
+/// State of `DataTable`
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TableState {
+    /// Sorted column and order
+    pub sort: Option<(usize, SortOrder)>,
+    /// Indexes of the selected rows in data
+    pub selected: BTreeSet<usize>,
+    /// Current page
+    pub page: usize,
+}
+
+impl TableState {
+    /// Toggle sort of column, ascending, descending then none
+    pub fn toggle_sort(&mut self, column: usize) {
+        self.sort = match self.sort {
+            Some((c, SortOrder::Asc)) if c == column => Some((c, SortOrder::Desc)),
+            Some((c, SortOrder::Desc)) if c == column => None,
+            _ => Some((column, SortOrder::Asc)),
+        };
+    }
+
+    /// Toggle selection of row
+    pub fn toggle_row(&mut self, row: usize) {
+        if !self.selected.remove(&row) {
+            self.selected.insert(row);
+        }
+    }
+}
+
+/// Width of the selection column
+const SELECT_WIDTH: Unit = Unit::Px(40.0);
+
+/// Pixels of absolute length
+fn px(u: Unit) -> Option<f64> {
+    Some(match u {
+        Unit::Px(v) => v,
+        Unit::Cm(v) => v * 96.0 / 2.54,
+        Unit::Mm(v) => v * 96.0 / 25.4,
+        Unit::Q(v) => v * 96.0 / 101.6,
+        Unit::In(v) => v * 96.0,
+        Unit::Pc(v) => v * 16.0,
+        Unit::Pt(v) => v * 96.0 / 72.0,
+        _ => return None,
+    })
+}
+
+/// Sum of widths, absolute lengths sum up in px, relative ones in their unit
+fn sum(a: Unit, b: Unit) -> Option<Unit> {
+    Some(match (a, b) {
+        (Unit::Em(a), Unit::Em(b)) => Unit::Em(a + b),
+        (Unit::Rem(a), Unit::Rem(b)) => Unit::Rem(a + b),
+        (Unit::Ch(a), Unit::Ch(b)) => Unit::Ch(a + b),
+        (Unit::Vw(a), Unit::Vw(b)) => Unit::Vw(a + b),
+        (Unit::Percent(a), Unit::Percent(b)) => Unit::Percent(a + b),
+        (a, b) => Unit::Px(px(a)? + px(b)?),
+    })
+}
+
+/// Table of typed rows with sorting, column sizing, selection and pagination
+pub struct DataTable<T> {
+    data: Rc<Vec<T>>,
+    columns: Vec<Column<T>>,
+    selectable: bool,
+    page_size: usize,
+    border: Option<Border>,
+    state: Reactive<TableState>,
+}
+
+impl<T> Default for DataTable<T> {
+    fn default() -> Self {
+        DataTable {
+            data: Rc::new(vec![]),
+            columns: vec![],
+            selectable: false,
+            page_size: 0,
+            border: None,
+            state: Reactive::default(),
+        }
+    }
+}
+
+impl<T: 'static> DataTable<T> {
+    /// New table
+    pub fn new() -> Self {
+        DataTable::default()
+    }
+
+    /// Shortcut of `DataTable::new().data(Vec<T>)`
+    pub fn with(data: Vec<T>) -> Self {
+        DataTable::new().data(data)
+    }
+
+    /// Set rows
+    pub fn data(mut self, data: Vec<T>) -> Self {
+        self.data = Rc::new(data);
+        self
+    }
+
+    /// Push column
+    pub fn column(mut self, column: Column<T>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Select rows with checkboxes
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Rows per page, no pagination if 0
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Set cell border
+    pub fn border(mut self, border: Border) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    /// Share state with parent, to read the selection for example
+    pub fn state(mut self, state: Reactive<TableState>) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Page count
+    pub fn pages(&self) -> usize {
+        match self.page_size {
+            0 => 1,
+            size => ((self.data.len() + size - 1) / size).max(1),
+        }
+    }
+
+    /// Indexes of the rows in current page, sorted
+    pub fn rows(&self) -> Vec<usize> {
+        let state = self.state.get();
+        let mut rows = (0..self.data.len()).collect::<Vec<usize>>();
+        if let Some((column, order)) = state.sort {
+            if let Some(sort) = self.columns.get(column).and_then(|c| c.sort.as_ref()) {
+                rows.sort_by(|a, b| {
+                    let ord = sort(&self.data[*a], &self.data[*b]);
+                    match order {
+                        SortOrder::Asc => ord,
+                        SortOrder::Desc => ord.reverse(),
+                    }
+                });
+            }
+        }
+
+        match self.page_size {
+            0 => rows,
+            size => rows
+                .into_iter()
+                .skip(state.page.min(self.pages() - 1) * size)
+                .take(size)
+                .collect(),
+        }
+    }
+
+    /// Widths of tracks, the selection track comes first, resizable columns
+    /// are sized by their cells
+    fn tracks(&self) -> Vec<Option<Unit>> {
+        let mut tracks = vec![];
+        if self.selectable {
+            tracks.push(Some(SELECT_WIDTH));
+        }
+        for column in self.columns.iter() {
+            tracks.push(match (column.width, column.resizable) {
+                (_, true) | (Some(Unit::Auto), _) | (Some(Unit::Fr(_)), _) => None,
+                (width, _) => width,
+            });
+        }
+
+        tracks
+    }
+
+    /// Column of track
+    fn column_of(&self, track: usize) -> Option<&Column<T>> {
+        track
+            .checked_sub(self.selectable as usize)
+            .and_then(|i| self.columns.get(i))
+    }
+
+    /// If track sticks at left, the selection track sticks with sticky columns
+    fn sticky(&self, track: usize) -> bool {
+        match self.column_of(track) {
+            Some(column) => column.sticky,
+            None => self.columns.iter().any(|c| c.sticky),
+        }
+    }
+
+    /// Left offset of sticky track, the sum of the sticky tracks before it
+    fn left(&self, track: usize) -> Option<Unit> {
+        let tracks = self.tracks();
+        let mut widths = (0..track)
+            .filter(|t| self.sticky(*t))
+            .filter_map(|t| tracks[t]);
+        let first = widths.next().unwrap_or(Unit::Px(0.0));
+        widths.try_fold(first, sum)
+    }
+
+    /// Cell of track, resizable columns resize horizontally and sticky tracks
+    /// stack at left
+    fn cell(&self, tag: &str, track: usize, child: Node) -> Node {
+        let mut style: Vec<Style> = self.border.clone().map(Into::into).unwrap_or_default();
+        let mut inline = vec![];
+        if let Some(column) = self.column_of(track).filter(|c| c.resizable) {
+            style.push(Style::Overflow(Overflow::Auto));
+            if let Some(width) = column.width {
+                style.push(Style::Width(width));
+            }
+            inline.push("resize: horizontal".to_string());
+        }
+
+        if self.sticky(track) {
+            style.push(Style::Position(Position::Sticky));
+            style.push(Style::BackgroundColor(Color::White));
+            if let Some(left) = self.left(track) {
+                style.push(Style::Left(left));
+            }
+        }
+
+        let mut attr = Attribute::new().tag(tag);
+        if !inline.is_empty() {
+            attr = attr.prop("style", inline.join("; "));
+        }
+        Node::default()
+            .children(vec![child])
+            .attr(attr)
+            .style(style)
+    }
+
+    /// Column widths
+    fn colgroup(&self) -> Node {
+        let cols = self
+            .tracks()
+            .into_iter()
+            .map(|width| {
+                let col = Node::default().attr(Attribute::new().tag("col"));
+                match width {
+                    Some(width) => col.style(vec![Style::Width(width)]),
+                    None => col,
+                }
+            })
+            .collect();
+
+        Node::default()
+            .children(cols)
+            .attr(Attribute::new().tag("colgroup"))
+    }
+
+    /// Selection checkbox
+    fn checkbox(
+        &self,
+        label: &str,
+        checked: bool,
+        f: impl Fn(&mut TableState, bool) + 'static,
+    ) -> Node {
+        let state = self.state.clone();
+        let input = Node::default().attr(
+            form::control("input", "", false)
+                .r#type("checkbox")
+                .prop("aria-label", label)
+                .flag("checked", checked),
+        );
+
+        GestureDetector::new(input)
+            .register(Gesture::Change, move |s| {
+                let checked = form::checked_of(&s);
+                state.update(|st| f(st, checked));
+            })
+            .into()
+    }
+
+    fn thead(&self, rows: &[usize]) -> Node {
+        let state = self.state.get();
+        let mut ths = vec![];
+        if self.selectable {
+            let all = !rows.is_empty() && rows.iter().all(|r| state.selected.contains(r));
+            let page = rows.to_vec();
+            let select = self.checkbox("Select all", all, move |st, checked| {
+                for r in page.iter() {
+                    if checked {
+                        st.selected.insert(*r);
+                    } else {
+                        st.selected.remove(r);
+                    }
+                }
+            });
+            ths.push(self.cell("th", 0, select));
+        }
+
+        let offset = self.selectable as usize;
+        for (i, column) in self.columns.iter().enumerate() {
+            let sort = match state.sort {
+                Some((c, SortOrder::Asc)) if c == i => "ascending",
+                Some((c, SortOrder::Desc)) if c == i => "descending",
+                _ => "none",
+            };
+
+            let mut th = self.cell("th", i + offset, Text::with(&column.title).into());
+            th.attr.props.insert("scope".into(), "col".into());
+            if column.sort.is_some() {
+                let (state, key) = (self.state.clone(), self.state.clone());
+                th.attr.props.insert("aria-sort".into(), sort.into());
+                th.attr.props.insert("tabindex".into(), "0".into());
+                // Sorts on Enter and Space as well
+                th = GestureDetector::new(th)
+                    .register(Gesture::Tap, move |_| state.update(|st| st.toggle_sort(i)))
+                    .register(Gesture::Key, move |s| {
+                        match s.get(b"key".as_ref()).map(|k| k.as_slice()) {
+                            Some(b"Enter") | Some(b" ") => key.update(|st| st.toggle_sort(i)),
+                            _ => {}
+                        }
+                    })
+                    .into();
+            }
+
+            // Header sticks at top
+            if !column.sticky {
+                th.style.push(Style::Position(Position::Sticky));
+            }
+            th.style.push(Style::Top(Unit::Px(0.0)));
+            ths.push(th);
+        }
+
+        Node::default()
+            .children(vec![Node::default()
+                .children(ths)
+                .attr(Attribute::new().tag("tr"))])
+            .attr(Attribute::new().tag("thead"))
+    }
+
+    fn tbody(&self, rows: &[usize]) -> Node {
+        let state = self.state.get();
+        let trs = rows
+            .iter()
+            .map(|r| {
+                let selected = state.selected.contains(r);
+                let mut tds = vec![];
+                if self.selectable {
+                    let r = *r;
+                    let select =
+                        self.checkbox("Select row", selected, move |st, _| st.toggle_row(r));
+                    tds.push(self.cell("td", 0, select));
+                }
+
+                let offset = self.selectable as usize;
+                for (i, column) in self.columns.iter().enumerate() {
+                    tds.push(self.cell("td", i + offset, (column.cell)(&self.data[*r])));
+                }
+
+                let mut attr = Attribute::new().tag("tr");
+                if self.selectable {
+                    attr = attr.prop("aria-selected", selected.to_string());
+                }
+                Node::default().children(tds).attr(attr)
+            })
+            .collect::<Vec<Node>>();
+
+        Node::default()
+            .children(trs)
+            .attr(Attribute::new().tag("tbody"))
+    }
+
+    fn pagination(&self) -> Node {
+        let (page, pages) = (self.state.get().page.min(self.pages() - 1), self.pages());
+        let turn = |label: &str, to: Option<usize>| -> Node {
+            let state = self.state.clone();
+            let button: Node = Button::with(Text::with(label))
+                .disabled(to.is_none())
+                .into();
+            match to {
+                Some(to) => GestureDetector::new(button)
+                    .register(Gesture::Tap, move |_| state.update(|st| st.page = to))
+                    .into(),
+                None => button,
+            }
+        };
+
+        Node::default()
+            .children(vec![
+                turn("Previous", page.checked_sub(1)),
+                Text::with(&format!("Page {} of {}", page + 1, pages)).into(),
+                turn("Next", Some(page + 1).filter(|p| *p < pages)),
+            ])
+            .attr(Attribute::new().tag("nav").prop("aria-label", "Pagination"))
+    }
+}
+
+impl<T: 'static> LifeCycle for DataTable<T> {
+    fn create(&self) -> Node {
+        let rows = self.rows();
+        let table = Node::default()
+            .children(vec![self.colgroup(), self.thead(&rows), self.tbody(&rows)])
+            .attr(Attribute::new().tag("table"))
+            .style(vec![Style::Width(Unit::Percent(100.0))]);
+
+        if self.page_size == 0 {
+            table
+        } else {
+            Node::default().children(vec![table, self.pagination()])
+        }
+    }
+}
+
+impl<T: 'static> Component for DataTable<T> {
+    fn subscribe(&self, listener: Closure<()>) -> Vec<Subscription> {
+        vec![self.state.subscribe(listener)]
+    }
+
+    fn restore(&self, old: &dyn Component) -> bool {
+        match old.as_any().downcast_ref::<Self>() {
+            Some(old) => {
+                self.state.restore(&old.state);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<T: 'static> Into<Node> for DataTable<T> {
+    fn into(self) -> Node {
+        Node::from_component(Rc::new(self))
+    }
+}
@@ -1,19 +1,28 @@
-use crate::widgets::ListTile;
+use crate::widgets::{form, ListTile};
 use elvis_core::{
     derive::Setter,
     option_to_style,
     style::Border,
-    value::{Color, FontFamily, FontStyle, TextAlign, Unit},
-    Attribute, Node, Style,
+    value::{Color, FontFamily, FontStyle, TextAlign, TextDecoration, Unit},
+    Attribute, Closure, Gesture, GestureDetector, Node, Reactive, StateKV, Style,
 };
+use elvis_derive::css;
+use std::sync::Arc;
 
 /// `Text` might be the most popular spider from Mars,
 /// Does it know the Great Ziggy Stardust?
-#[derive(Default, Setter)]
+#[derive(Setter)]
+#[setter(default)]
 pub struct Text {
     /// Plain text
     #[skip]
     pub text: String,
+    /// Element tag
+    #[setter(default = "p")]
+    pub tag: String,
+    /// Inline children after the text
+    #[setter(each = "child")]
+    pub children: Vec<Node>,
     /// Bold text
     pub bold: bool,
     /// The color of the text
@@ -49,13 +58,15 @@ impl Text {
 
 impl Into<Node> for Text {
     fn into(mut self) -> Node {
-        let mut child = Node::default();
-        child.attr.tag = "plain".into();
-        child.attr.text = self.text.to_string();
+        let mut children = vec![];
+        if !self.text.is_empty() || self.children.is_empty() {
+            children.push(Node::text(&self.text));
+        }
+        children.append(&mut self.children);
 
         let mut styles: Vec<Style> = vec![];
         if self.italic {
-            styles.push(Style::FontStyle(FontStyle::Normal));
+            styles.push(Style::FontStyle(FontStyle::Italic));
         }
 
         if self.bold {
@@ -74,27 +85,186 @@ impl Into<Node> for Text {
             ],
         }
 
-        let mut node = Node::default().children(vec![child]).style(styles);
+        Node::default()
+            .children(children)
+            .style(styles)
+            .attr(Attribute::new().tag(&self.tag))
+    }
+}
+
+/// Run of text in `RichText`, spans nest with their own styles
+#[derive(Default, Setter)]
+pub struct TextSpan {
+    /// Plain text
+    #[skip]
+    pub text: String,
+    /// Nested spans
+    #[setter(each = "child")]
+    pub children: Vec<TextSpan>,
+    /// Bold text
+    pub bold: bool,
+    /// The color of the text
+    pub color: Option<Color>,
+    /// Italic text
+    pub italic: bool,
+    /// Text size
+    pub size: Option<Unit>,
+    /// Text weight
+    pub weight: Option<Unit>,
+    /// Text decoration
+    pub decoration: Option<TextDecoration>,
+    /// Tap handler, for inline links, runs on Enter as well
+    #[skip]
+    pub on_tap: Option<Closure<StateKV>>,
+}
+
+impl TextSpan {
+    /// Shortcut of `TextSpan::new().text(&str)`
+    pub fn with(s: &str) -> Self {
+        TextSpan::new().text(s)
+    }
+
+    /// Set Text
+    pub fn text(mut self, s: &str) -> Self {
+        self.text = s.into();
+        self
+    }
+
+    /// Set tap handler
+    pub fn on_tap(mut self, f: impl Fn(StateKV) + 'static) -> Self {
+        self.on_tap = Some(Arc::new(f));
+        self
+    }
+}
+
+impl From<&str> for TextSpan {
+    fn from(s: &str) -> TextSpan {
+        TextSpan::with(s)
+    }
+}
+
+impl Into<Node> for TextSpan {
+    fn into(mut self) -> Node {
+        let mut children = vec![];
+        if !self.text.is_empty() {
+            children.push(Node::text(&self.text));
+        }
+        children.extend(self.children.into_iter().map(Into::into));
+
+        let mut styles: Vec<Style> = vec![];
+        if self.italic {
+            styles.push(Style::FontStyle(FontStyle::Italic));
+        }
+
+        if self.bold {
+            self.weight = Some(Unit::None(700.0));
+        }
+
+        option_to_style! {
+            styles, [
+                (Color, self.color),
+                (FontWeight, self.weight),
+                (FontSize, self.size),
+                (TextDecoration, self.decoration),
+            ],
+        }
 
-        // Set Tag
-        node.attr.tag = "p".into();
-        node
+        let node = Node::default()
+            .children(children)
+            .style(styles)
+            .attr(Attribute::new().tag("span"));
+        match self.on_tap {
+            Some(f) => {
+                // Links activate on Enter as well
+                let enter = f.clone();
+                GestureDetector::new(
+                    node.attr(
+                        Attribute::new()
+                            .tag("span")
+                            .prop("role", "link")
+                            .prop("tabindex", "0"),
+                    ),
+                )
+                .register(Gesture::Tap, move |s| f(s))
+                .register(Gesture::Key, move |s| {
+                    if s.get(b"key".as_ref()).map(|k| k.as_slice()) == Some(b"Enter".as_ref()) {
+                        enter(s);
+                    }
+                })
+                .into()
+            }
+            None => node,
+        }
     }
 }
 
-/// Text Field
+/// Paragraph of `TextSpan`s with mixed formatting
 #[derive(Default, Setter)]
+pub struct RichText {
+    /// Text spans
+    #[setter(each = "span")]
+    pub spans: Vec<TextSpan>,
+    /// Text size
+    pub size: Option<Unit>,
+    /// Text height
+    pub height: Option<Unit>,
+    /// Font Family
+    pub family: Option<FontFamily>,
+    /// Text Align
+    pub align: Option<TextAlign>,
+}
+
+impl Into<Node> for RichText {
+    fn into(self) -> Node {
+        let mut styles: Vec<Style> = vec![];
+        option_to_style! {
+            styles, [
+                (FontSize, self.size),
+                (LineHeight, self.height),
+                (FontFamily, self.family),
+                (TextAlign, self.align),
+            ],
+        }
+
+        Node::default()
+            .children(self.spans.into_iter().map(Into::into).collect())
+            .style(styles)
+            .attr(Attribute::new().tag("p"))
+    }
+}
+
+/// Text Field, an `input` element between leading and trailing widgets, the
+/// bound cell updates on change
+#[derive(Setter)]
+#[setter(default)]
 pub struct TextField {
     /// Leading widget
     pub leading: Node,
     /// Trailing widget
     pub trailing: Node,
-    /// Plain text
+    /// Text style of the input
     pub text: Text,
+    /// Label text
+    pub label: String,
+    /// Field name
+    pub name: String,
+    /// Input type
+    #[setter(default = "text")]
+    pub r#type: String,
+    /// Value if not bound
+    pub value: String,
+    /// Placeholder
+    pub placeholder: String,
+    /// Bound cell
+    pub bind: Option<Reactive<String>>,
+    /// Disable input
+    pub disabled: bool,
+    /// Read only
+    pub readonly: bool,
 }
 
 impl TextField {
-    /// Shortcut of `Text::new().text(&str)`
+    /// Shortcut of `TextField::new().text(Text)`
     pub fn with(t: Text) -> Self {
         TextField::new().text(t)
     }
@@ -103,18 +273,33 @@ impl TextField {
 impl Into<Node> for TextField {
     fn into(self) -> Node {
         let mut style: Vec<Style> = Border::default().into();
-        style.append(&mut vec![
-            Style::Width(Unit::Percent(100.0)),
-            Style::OutlineWidth(Unit::None(0.0)),
-        ]);
+        style.append(&mut css! {
+            width: 100%;
+            outline-width: 0;
+        });
+
+        // Take the styles of text only, inputs have no children
+        let text: Node = self.text.into();
+        let value = self.bind.as_ref().map_or(self.value, |b| b.get());
+        let input = Node::default()
+            .attr(
+                form::control("input", &self.name, self.disabled)
+                    .r#type(self.r#type)
+                    .prop("value", value)
+                    .prop("placeholder", self.placeholder)
+                    .flag("readonly", self.readonly),
+            )
+            .style(text.style)
+            .append_style(style);
 
         ListTile::new()
             .leading(self.leading)
-            .text(
-                Into::<Node>::into(self.text)
-                    .attr(Attribute::new().tag("input"))
-                    .append_style(style),
-            )
+            .text(form::labeled(
+                &self.label,
+                form::bind(input, Gesture::Change, self.bind, |s| {
+                    Some(form::value_of(s))
+                }),
+            ))
             .trailing(self.trailing)
             .into()
     }
@@ -0,0 +1,138 @@
+//! Toasts
+//!
+//! `ToastHost` shows the visible toasts of `Toasts`, `Scaffold` mounts it.
+use crate::widgets::{
+    layouts::{Col, Row},
+    Portal,
+};
+use elvis_core::{
+    value::{layouts::FlexPosition, BorderRadius, Color, Position, Unit, VecUnit},
+    Attribute, Gesture, GestureDetector, Node, Reactive, Severity, Style, Toast, Toasts,
+};
+use elvis_derive::Component;
+
+/// Button dismisses toast after tap
+fn button(text: &str, label: &str, id: usize, f: impl Fn() + 'static) -> Node {
+    let mut attr = Attribute::new().tag("button").r#type("button");
+    if !label.is_empty() {
+        attr = attr.prop("aria-label", label);
+    }
+
+    GestureDetector::new(
+        Node::default()
+            .children(vec![Node::text(text)])
+            .attr(attr)
+            .style(vec![Style::Color(Color::White)]),
+    )
+    .register(Gesture::Tap, move |_| {
+        f();
+        Toasts::dismiss(id);
+    })
+    .into()
+}
+
+/// Snackbar, shows a toast with its action
+pub struct Snackbar {
+    /// Toast to show
+    pub toast: Toast,
+}
+
+impl Snackbar {
+    /// Snackbar of toast
+    pub fn with(toast: Toast) -> Self {
+        Snackbar { toast }
+    }
+}
+
+impl Into<Node> for Snackbar {
+    fn into(self) -> Node {
+        let toast = self.toast;
+        let (role, background) = match toast.severity {
+            Severity::Info => ("status", Color::Black),
+            Severity::Success => ("status", Color::Green),
+            Severity::Warning => ("alert", Color::Orange),
+            Severity::Error => ("alert", Color::Red),
+        };
+
+        let mut attr = Attribute::new()
+            .prop("role", role)
+            .prop("data-toast", toast.id.to_string())
+            .prop("data-severity", toast.severity.to_string());
+        if let Some(ms) = toast.duration {
+            attr = attr.prop("data-timeout", ms.to_string());
+        }
+
+        let mut children = vec![Node::default()
+            .children(vec![Node::text(&toast.message)])
+            .attr(Attribute::new().tag("span"))
+            .style(vec![Style::FlexGrow(Unit::None(1.0))])];
+        if let Some((label, f)) = toast.action {
+            children.push(button(&label, "", toast.id, move || f(())));
+        }
+        children.push(button("×", "Dismiss", toast.id, || {}));
+
+        let row: Node = Row::new().children(children).into();
+        row.attr(attr).style(vec![
+            Style::AlignItems(FlexPosition::Center),
+            Style::MinWidth(Unit::Px(288.0)),
+            Style::Padding(VecUnit(vec![Unit::Px(8.0), Unit::Px(16.0)])),
+            Style::MarginTop(Unit::Px(8.0)),
+            Style::BorderRadius(BorderRadius::default().all(Unit::Px(4.0))),
+            Style::BackgroundColor(background),
+            Style::Color(Color::White),
+        ])
+    }
+}
+
+/// Toast host, shows the visible toasts at the bottom left
+///
+/// The hosts of multiple scaffolds render the same toasts into the overlay
+/// root, where only the first one shows.
+#[derive(Component)]
+pub struct ToastHost {
+    /// Visible toasts
+    #[state]
+    pub toasts: Reactive<Vec<Toast>>,
+}
+
+impl Default for ToastHost {
+    fn default() -> ToastHost {
+        ToastHost {
+            toasts: Toasts::visible(),
+        }
+    }
+}
+
+impl ToastHost {
+    /// New toast host
+    pub fn new() -> Self {
+        ToastHost::default()
+    }
+}
+
+impl ToastHost {
+    fn render(&self) -> Node {
+        let toasts = self.toasts.get();
+        if toasts.is_empty() {
+            return Node::default();
+        }
+
+        let list: Node = Col::new()
+            .children(
+                toasts
+                    .into_iter()
+                    .map(|t| Snackbar::with(t).into())
+                    .collect::<Vec<Node>>(),
+            )
+            .into();
+        Portal::with(
+            list.attr(Attribute::new().prop("data-toast-host", ""))
+                .style(vec![
+                    Style::Position(Position::Fixed),
+                    Style::Left(Unit::Px(16.0)),
+                    Style::Bottom(Unit::Px(16.0)),
+                ]),
+        )
+        .into()
+    }
+}
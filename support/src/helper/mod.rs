@@ -1,3 +1,5 @@
 mod option;
+mod vec;
 
 pub use option::extract_type_from_option;
+pub use vec::extract_type_from_vec;
@@ -0,0 +1,21 @@
+use syn::{GenericArgument, PathArguments, Type};
+
+/// Extract item type from vec
+pub fn extract_type_from_vec(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(typepath) if typepath.qself.is_none() => typepath.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != "Vec" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(params) => match params.args.first()? {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -9,7 +9,12 @@ mod setter;
 mod style_wrapper;
 
 /// Basic elvis attr macro
-#[proc_macro_derive(Setter, attributes(skip))]
+///
+/// Structs with `#[setter(default)]` get `Default`, from the values of their
+/// `#[setter(default = expr)]` fields, or `Default::default()`.
+/// `#[setter(validate = path, error = Type)]` setters return `elvis_core::Error`
+/// if `error` is absent.
+#[proc_macro_derive(Setter, attributes(skip, prop, state, setter))]
 pub fn setter(input: TokenStream) -> TokenStream {
     setter::parse(input)
 }
@@ -1,74 +1,266 @@
 use proc_macro::TokenStream;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use proc_macro_crate::crate_name;
 use quote::quote;
-use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Field, Fields, Type};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Attribute, Data, DataStruct, DeriveInput, Error, Expr, ExprLit, ExprPath, Field, Fields, Lit,
+    Path, Result, Token,
+};
 
-/// Basic elvis attr macro
-pub fn parse(input: TokenStream) -> TokenStream {
+/// `#[setter(each = "child", default = expr, validate = path, error = Type)]`
+#[derive(Default)]
+struct Options {
+    each: Option<Ident>,
+    default: Option<Expr>,
+    validate: Option<Expr>,
+    error: Option<Path>,
+}
+
+/// Argument of `#[setter(...)]`, `name = value` or `name`
+struct Arg {
+    name: Ident,
+    value: Option<Expr>,
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Arg { name, value })
+    }
+}
+
+/// Arguments of the `#[setter(...)]` attributes
+fn args(attrs: &[Attribute]) -> Result<Vec<Arg>> {
+    let mut args = vec![];
+    for attr in attrs.iter().filter(|a| a.path.is_ident("setter")) {
+        args.extend(attr.parse_args_with(Punctuated::<Arg, Token![,]>::parse_terminated)?);
+    }
+
+    Ok(args)
+}
+
+/// If the struct opts in `Default` with `#[setter(default)]`
+fn derive_default(attrs: &[Attribute]) -> Result<bool> {
+    let mut default = false;
+    for arg in args(attrs)? {
+        match arg {
+            Arg { name, value: None } if name == "default" => default = true,
+            Arg { name, .. } => return Err(Error::new_spanned(name, "expected `default`")),
+        }
+    }
+
+    Ok(default)
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute]) -> Result<Options> {
+        let mut opts = Options::default();
+        for Arg { name: ident, value } in args(attrs)? {
+            let name = ident.to_string();
+            if !["each", "default", "validate", "error"].contains(&name.as_str()) {
+                return Err(Error::new_spanned(
+                    ident,
+                    "expected `each`, `default`, `validate` or `error`",
+                ));
+            }
+
+            let value = value
+                .ok_or_else(|| Error::new_spanned(&ident, format!("expected `{} = ...`", name)))?;
+            match name.as_str() {
+                "each" => {
+                    opts.each = Some(match &value {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(s), ..
+                        }) => s.parse()?,
+                        Expr::Path(ExprPath { path, .. }) if path.get_ident().is_some() => {
+                            path.get_ident().unwrap().clone()
+                        }
+                        value => return Err(Error::new_spanned(value, "expected a method name")),
+                    })
+                }
+                "default" => opts.default = Some(value),
+                "validate" => opts.validate = Some(value),
+                _ => {
+                    opts.error = Some(match value {
+                        Expr::Path(ExprPath { path, .. }) => path,
+                        value => return Err(Error::new_spanned(value, "expected a type")),
+                    })
+                }
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+/// Error of validating setters, `elvis_core::Error` or the `elvis::Error` it
+/// converts into if the caller only depends on `elvis`
+fn error() -> TokenStream2 {
+    let name = crate_name("elvis-core")
+        .or_else(|_| crate_name("elvis"))
+        .unwrap_or_else(|_| "elvis".into());
+    let name = Ident::new(&name, Span::call_site());
+    quote! { ::#name::Error }
+}
+
+/// Doc comments of field, or `Set <field>`
+fn docs(field: &Field, name: &Ident) -> Vec<TokenStream2> {
+    let docs = field
+        .attrs
+        .iter()
+        .filter(|a| a.path.is_ident("doc"))
+        .map(|a| quote! { #a })
+        .collect::<Vec<TokenStream2>>();
+
+    if docs.is_empty() {
+        let doc = format!("Set {}", name);
+        vec![quote! { #[doc = #doc] }]
+    } else {
+        docs
+    }
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
     const SKIP_FIELD: &str = "skip";
-    let input = parse_macro_input!(input as DeriveInput);
+    const STATE_FIELD: &str = "state";
+    const PROP_FIELD: &str = "prop";
 
-    // Get fields without `skip` attribute
-    let fields = match &input.data {
+    let named = match &input.data {
         Data::Struct(DataStruct {
             fields: Fields::Named(fields),
             ..
         }) => &fields.named,
-        _ => panic!("expected a struct with named fields"),
-    }
-    .iter()
-    .filter(|f| {
-        f.attrs
-            .iter()
-            .find(|a| a.path.is_ident(SKIP_FIELD))
-            .is_none()
-    })
-    .collect::<Vec<&Field>>();
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "expected a struct with named fields",
+            ))
+        }
+    };
+
+    // Components only expose `prop` fields if there are any
+    let has = |f: &Field, attr: &str| f.attrs.iter().any(|a| a.path.is_ident(attr));
+    let props = named.iter().any(|f| has(f, PROP_FIELD));
+
+    // Get fields without `skip` or `state` attribute
+    let fields = named
+        .iter()
+        .filter(|f| !has(f, SKIP_FIELD) && !has(f, STATE_FIELD))
+        .filter(|f| !props || has(f, PROP_FIELD))
+        .collect::<Vec<&Field>>();
 
     let struct_name = &input.ident;
     let struct_new_doc = format!("new {}", struct_name);
 
-    let mut field_type: Vec<&Type> = vec![];
-    let mut field_name: Vec<&Option<Ident>> = vec![];
-    let mut field_doc: Vec<String> = vec![];
-    let mut optional_field_type: Vec<&Type> = vec![];
-    let mut optional_field_name: Vec<&Option<Ident>> = vec![];
-    let mut optional_field_doc: Vec<String> = vec![];
-
-    fields.iter().for_each(|field| {
-        if let Some(ty) = super::helper::extract_type_from_option(&field.ty) {
-            optional_field_type.push(&ty);
-            optional_field_name.push(&field.ident);
-            optional_field_doc.push(format!("Set {}", field.ident.clone().unwrap().to_string()));
-        } else {
-            field_type.push(&field.ty);
-            field_name.push(&field.ident);
-            field_doc.push(format!("Set {}", field.ident.clone().unwrap().to_string()));
-        }
-    });
-
-    TokenStream::from(quote! {
-        impl #struct_name {
-            #[doc = #struct_new_doc]
-            pub fn new() -> #struct_name {
-                #struct_name::default()
+    // `new` and `Default` share the `default` values
+    let derive_default = derive_default(&input.attrs)?;
+    let mut defaults = vec![];
+    for field in named.iter() {
+        let name = field.ident.as_ref().unwrap();
+        defaults.push(match Options::parse(&field.attrs)?.default {
+            Some(default) if derive_default => quote! { #name: (#default).into(), },
+            Some(default) => {
+                return Err(Error::new_spanned(
+                    default,
+                    "`default` values need `#[setter(default)]` on the struct",
+                ))
             }
+            None => quote! { #name: Default::default(), },
+        });
+    }
+
+    let mut setters = vec![];
+    for field in fields {
+        let opts = Options::parse(&field.attrs)?;
+        let name = field.ident.as_ref().unwrap();
+        let docs = docs(field, name);
+        let (ty, value) = match super::helper::extract_type_from_option(&field.ty) {
+            Some(ty) => (ty, quote! { Some(v) }),
+            None => (&field.ty, quote! { v }),
+        };
 
-            #(
-                #[doc = #field_doc]
-                pub fn #field_name(mut self, v: impl Into<#field_type>) -> Self {
-                    self.#field_name = v.into();
+        setters.push(match &opts.validate {
+            Some(validate) => {
+                let error = match &opts.error {
+                    Some(error) => quote! { #error },
+                    None => error(),
+                };
+                quote! {
+                    #(#docs)*
+                    pub fn #name(mut self, v: impl Into<#ty>) -> Result<Self, #error> {
+                        let v = v.into();
+                        (#validate)(&v)?;
+                        self.#name = #value;
+                        Ok(self)
+                    }
+                }
+            }
+            None => quote! {
+                #(#docs)*
+                pub fn #name(mut self, v: impl Into<#ty>) -> Self {
+                    let v = v.into();
+                    self.#name = #value;
                     self
                 }
-            )*
+            },
+        });
 
-            #(
-                #[doc = #optional_field_doc]
-                pub fn #optional_field_name(mut self, v: impl Into<#optional_field_type>) -> Self {
-                    self.#optional_field_name = Some(v.into());
+        if let Some(each) = &opts.each {
+            let item = super::helper::extract_type_from_vec(&field.ty)
+                .ok_or_else(|| Error::new_spanned(&field.ty, "`each` expects a `Vec` field"))?;
+            let doc = format!("Push one item to {}", name);
+            setters.push(quote! {
+                #[doc = #doc]
+                pub fn #each(mut self, v: impl Into<#item>) -> Self {
+                    self.#name.push(v.into());
                     self
                 }
-            )*
+            });
+        }
+    }
+
+    // Structs with `#[setter(default)]` get `Default` from the `default` values
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let default = if derive_default {
+        quote! {
+            impl #impl_generics Default for #struct_name #ty_generics #where_clause {
+                fn default() -> Self {
+                    #struct_name {
+                        #(#defaults)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #default
+
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #[doc = #struct_new_doc]
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #(#setters)*
         }
     })
 }
+
+/// Basic elvis attr macro
+pub fn parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(expand(&input).unwrap_or_else(|e| e.to_compile_error()))
+}
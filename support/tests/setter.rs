@@ -0,0 +1,71 @@
+use elvis_core_support::Setter;
+
+#[derive(Debug, PartialEq)]
+struct Invalid;
+
+fn positive(v: &i32) -> Result<(), Invalid> {
+    if *v > 0 {
+        Ok(())
+    } else {
+        Err(Invalid)
+    }
+}
+
+#[derive(Setter)]
+#[setter(default)]
+struct Button {
+    #[setter(default = "OK")]
+    text: String,
+    #[setter(each = "class")]
+    classes: Vec<String>,
+    #[setter(default = 1, validate = positive, error = Invalid)]
+    size: i32,
+    label: Option<String>,
+    #[skip]
+    #[setter(default = true)]
+    enabled: bool,
+}
+
+#[derive(Default, Setter)]
+struct Plain {
+    text: String,
+}
+
+#[test]
+fn defaults_from_setter_values() {
+    let button = Button::default();
+    assert_eq!(button.text, "OK");
+    assert_eq!(button.size, 1);
+    assert!(button.classes.is_empty());
+    assert_eq!(button.label, None);
+    assert!(button.enabled);
+}
+
+#[test]
+fn setters() {
+    let button = Button::new()
+        .text("Cancel")
+        .class("primary")
+        .class("large")
+        .label("cancel");
+    assert_eq!(button.text, "Cancel");
+    assert_eq!(button.classes, vec!["primary", "large"]);
+    assert_eq!(button.label.as_deref(), Some("cancel"));
+}
+
+#[test]
+fn validated_setters() {
+    assert_eq!(Button::new().size(3).map(|b| b.size), Ok(3));
+    assert_eq!(Button::new().size(0).err(), Some(Invalid));
+}
+
+#[test]
+fn keeps_own_default() {
+    assert_eq!(Plain::new().text("a").text, "a");
+}
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
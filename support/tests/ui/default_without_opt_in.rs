@@ -0,0 +1,9 @@
+use elvis_core_support::Setter;
+
+#[derive(Setter)]
+struct Button {
+    #[setter(default = "OK")]
+    text: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use elvis_core_support::Setter;
+
+#[derive(Setter)]
+#[setter(each = "child")]
+struct Button {
+    text: String,
+}
+
+fn main() {}
@@ -0,0 +1,98 @@
+//! Canvas backed by `CanvasRenderingContext2d`
+use elvis_core::{Context2d, Drawing, Paint, Path, PathOp, Transform};
+use std::str::FromStr;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CanvasRenderingContext2d, Element, HtmlCanvasElement};
+
+/// 2D context of canvas element
+struct Context(CanvasRenderingContext2d);
+
+impl Context {
+    /// Css color or canvas gradient
+    fn style(&self, paint: &Paint) -> JsValue {
+        let (gradient, stops) = match paint {
+            Paint::Color(color) => return JsValue::from_str(&color.to_string()),
+            Paint::Linear(x0, y0, x1, y1, stops) => {
+                (Ok(self.0.create_linear_gradient(*x0, *y0, *x1, *y1)), stops)
+            }
+            Paint::Radial(x0, y0, r0, x1, y1, r1, stops) => (
+                self.0.create_radial_gradient(*x0, *y0, *r0, *x1, *y1, *r1),
+                stops,
+            ),
+        };
+
+        match gradient {
+            Ok(gradient) => {
+                for (offset, color) in stops {
+                    let _ = gradient.add_color_stop(*offset as f32, &color.to_string());
+                }
+                gradient.into()
+            }
+            Err(e) => e,
+        }
+    }
+}
+
+impl Context2d for Context {
+    fn save(&mut self) {
+        self.0.save();
+    }
+
+    fn restore(&mut self) {
+        self.0.restore();
+    }
+
+    fn transform(&mut self, t: &Transform) {
+        let [a, b, c, d, e, f] = t.0;
+        let _ = self.0.transform(a, b, c, d, e, f);
+    }
+
+    fn path(&mut self, path: &Path) {
+        self.0.begin_path();
+        for op in path.0.iter() {
+            match *op {
+                PathOp::MoveTo(x, y) => self.0.move_to(x, y),
+                PathOp::LineTo(x, y) => self.0.line_to(x, y),
+                PathOp::QuadTo(cx, cy, x, y) => self.0.quadratic_curve_to(cx, cy, x, y),
+                PathOp::CubicTo(a, b, c, d, x, y) => self.0.bezier_curve_to(a, b, c, d, x, y),
+                PathOp::Arc(x, y, r, s, e, ccw) => {
+                    let _ = self.0.arc_with_anticlockwise(x, y, r, s, e, ccw);
+                }
+                PathOp::Rect(x, y, w, h) => self.0.rect(x, y, w, h),
+                PathOp::Close => self.0.close_path(),
+            }
+        }
+    }
+
+    fn fill(&mut self, paint: &Paint) {
+        self.0.set_fill_style(&self.style(paint));
+        self.0.fill();
+    }
+
+    fn stroke(&mut self, paint: &Paint, width: f64) {
+        self.0.set_stroke_style(&self.style(paint));
+        self.0.set_line_width(width);
+        self.0.stroke();
+    }
+
+    fn text(&mut self, text: &str, x: f64, y: f64, font: &str, paint: &Paint) {
+        self.0.set_fill_style(&self.style(paint));
+        self.0.set_font(font);
+        let _ = self.0.fill_text(text, x, y);
+    }
+}
+
+/// Draw the drawing of `data-drawing` on canvas element
+pub fn draw(el: &Element, drawing: &str) -> Result<(), JsValue> {
+    let drawing = Drawing::from_str(drawing).map_err(|e| JsValue::from_str(&format!("{:?}", e)))?;
+    let ctx = match el.dyn_ref::<HtmlCanvasElement>() {
+        Some(canvas) => canvas.get_context("2d")?,
+        None => None,
+    };
+
+    if let Some(ctx) = ctx {
+        drawing.draw(&mut Context(ctx.dyn_into::<CanvasRenderingContext2d>()?));
+    }
+
+    Ok(())
+}
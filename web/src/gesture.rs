@@ -1,26 +1,61 @@
 use crate::event::EventListener;
-use elvis_core::{Gesture, Node};
+use elvis_core::{Gesture, Namespace, Node, StateKV};
 use std::collections::HashMap;
 use std::{cell::RefCell, rc::Rc};
-use wasm_bindgen::JsValue;
-use web_sys::{Document, Element};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+    Document, Element, Event, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement,
+    KeyboardEvent, MouseEvent,
+};
 
-/// Bind gesture to node
-pub fn bind(node: &Rc<RefCell<Node>>, dom: &Document) -> Result<Element, JsValue> {
+/// Bind gesture to node, creates the element in namespace
+pub fn bind(node: &Rc<RefCell<Node>>, dom: &Document, ns: Namespace) -> Result<Element, JsValue> {
     if node.borrow().attr.tag.is_empty() {
         node.borrow_mut().attr.tag = "div".to_string();
     }
 
-    let this = dom.create_element(&node.borrow().attr.tag)?;
+    let this = match ns {
+        Namespace::Html => dom.create_element(&node.borrow().attr.tag)?,
+        ns => dom.create_element_ns(Some(ns.uri()), &node.borrow().attr.tag)?,
+    };
+    // Only canvas nodes hit their regions by position
+    let drawing = node.borrow().attr.props.contains_key("data-drawing");
+    let routed = node.borrow().attr.props.contains_key("data-route");
     if let Some(gestures) = &node.borrow().gesture {
         for (m, f) in gestures.clone() {
             let state = node.borrow().state.clone();
-            EventListener::new(&this, parse_gesture(&m), move |_| {
-                if let Some(s) = &state {
-                    f(s.clone());
-                } else {
-                    f(HashMap::new());
+            EventListener::new(&this, parse_gesture(&m), move |e| {
+                let mut s = state.clone().unwrap_or_else(HashMap::new);
+                match m {
+                    Gesture::Input | Gesture::Change | Gesture::Blur => form_value(e, &mut s),
+                    Gesture::Tap if routed => {
+                        // Routed links push their routes, unless opened in new tabs
+                        if let Some(e) = e.dyn_ref::<MouseEvent>() {
+                            if e.ctrl_key() || e.meta_key() || e.shift_key() {
+                                return;
+                            }
+                        }
+                        e.prevent_default();
+                    }
+                    Gesture::Tap if drawing => {
+                        if let Some(e) = e.dyn_ref::<MouseEvent>() {
+                            s.insert(b"x".to_vec(), e.offset_x().to_string().into_bytes());
+                            s.insert(b"y".to_vec(), e.offset_y().to_string().into_bytes());
+                        }
+                    }
+                    Gesture::Submit => e.prevent_default(),
+                    Gesture::Scroll => scroll_value(e, &mut s),
+                    Gesture::Load => load_status(e, "loaded"),
+                    Gesture::Error => load_status(e, "failed"),
+                    Gesture::Key => {
+                        if let Some(e) = e.dyn_ref::<KeyboardEvent>() {
+                            s.insert(b"key".to_vec(), e.key().into_bytes());
+                        }
+                    }
+                    _ => {}
                 }
+
+                f(s);
             })
             .forget();
         }
@@ -29,9 +64,93 @@ pub fn bind(node: &Rc<RefCell<Node>>, dom: &Document) -> Result<Element, JsValue
     Ok(this)
 }
 
+/// Put the value of form control into state
+fn form_value(e: &Event, s: &mut StateKV) {
+    let target = match e.target() {
+        Some(target) => target,
+        None => return,
+    };
+
+    if let Some(input) = target.dyn_ref::<HtmlInputElement>() {
+        s.insert(b"name".to_vec(), input.name().into_bytes());
+        s.insert(b"type".to_vec(), input.type_().into_bytes());
+        s.insert(b"value".to_vec(), input.value().into_bytes());
+        s.insert(
+            b"checked".to_vec(),
+            input.checked().to_string().into_bytes(),
+        );
+    } else if let Some(select) = target.dyn_ref::<HtmlSelectElement>() {
+        s.insert(b"name".to_vec(), select.name().into_bytes());
+        s.insert(b"value".to_vec(), select.value().into_bytes());
+    } else if let Some(textarea) = target.dyn_ref::<HtmlTextAreaElement>() {
+        s.insert(b"name".to_vec(), textarea.name().into_bytes());
+        s.insert(b"value".to_vec(), textarea.value().into_bytes());
+    }
+}
+
+/// Put the scroll offsets of element into state
+fn scroll_value(e: &Event, s: &mut StateKV) {
+    if let Some(el) = e.target().as_ref().and_then(|t| t.dyn_ref::<Element>()) {
+        for (k, v) in &[
+            ("scroll_top", el.scroll_top()),
+            ("scroll_left", el.scroll_left()),
+            ("client_height", el.client_height()),
+            ("client_width", el.client_width()),
+            ("scroll_height", el.scroll_height()),
+            ("scroll_width", el.scroll_width()),
+        ] {
+            s.insert(k.as_bytes().to_vec(), v.to_string().into_bytes());
+        }
+
+        // Heights of the rendered items of virtual views, `index:height,...`
+        if el.has_attribute("data-virtual") {
+            if let Ok(items) = el.query_selector_all("[data-index]") {
+                let heights = (0..items.length())
+                    .filter_map(|i| items.get(i)?.dyn_into::<Element>().ok())
+                    .filter_map(|item| {
+                        let index = item.get_attribute("data-index")?;
+                        Some(format!(
+                            "{}:{}",
+                            index,
+                            item.get_bounding_client_rect().height()
+                        ))
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",");
+                s.insert(b"heights".to_vec(), heights.into_bytes());
+            }
+        }
+    }
+}
+
+/// Update the `data-status` of the image wrapper, it shows the placeholder or
+/// fallback by status
+fn load_status(e: &Event, status: &str) {
+    let parent = e
+        .target()
+        .as_ref()
+        .and_then(|t| t.dyn_ref::<Element>())
+        .and_then(|el| el.parent_element());
+    if let Some(parent) = parent {
+        if parent.has_attribute("data-status") {
+            let _ = parent.set_attribute("data-status", status);
+        }
+    }
+}
+
 fn parse_gesture(g: &Gesture) -> &'static str {
     match g {
         Gesture::Tap => "click",
         Gesture::LongTap => "click",
+        Gesture::Input => "input",
+        Gesture::Change => "change",
+        Gesture::Blur => "focusout",
+        Gesture::Submit => "submit",
+        Gesture::Scroll => "scroll",
+        Gesture::Enter => "mouseenter",
+        Gesture::Leave => "mouseleave",
+        Gesture::Key => "keydown",
+        Gesture::Load => "load",
+        Gesture::Error => "error",
     }
 }
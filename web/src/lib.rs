@@ -4,20 +4,22 @@
 //!
 //! [1]: https://clearloop.github.io/elvis
 #![warn(missing_docs)]
+mod canvas;
 mod driver;
 mod err;
 pub mod event;
 mod gesture;
 mod node;
 mod page;
+mod portal;
 mod router;
 mod style;
 
-use self::style::StyleSheet;
-pub use self::{driver::Driver, err::Error, page::Page, router::Router};
+pub use self::{driver::Driver, err::Error, page::Page, router::Router, style::StyleSheet};
 
 /// Re-exports wasm-bindgen
 pub mod wasm_bindgen_re_exports {
     pub use wasm_bindgen;
     pub use wasm_bindgen::prelude::*;
+    pub use wasm_bindgen_futures::spawn_local;
 }
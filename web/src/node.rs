@@ -1,15 +1,15 @@
 //! node opt
-use crate::{gesture, style};
-use elvis_core::{Class, Node};
+use crate::{canvas, gesture, portal, style};
+use elvis_core::{Class, Namespace, Node};
 use std::{cell::RefCell, rc::Rc};
-use wasm_bindgen::JsValue;
-use web_sys::{Document, Element};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Document, Element, HtmlTemplateElement};
 
 fn parse_class(classes: &[Class], id: &str) -> String {
     let mut r = "".to_string();
     classes.iter().for_each(|c| {
         r.push_str(" ");
-        r.push_str(style::parse_class(c));
+        r.push_str(&style::parse_class(c));
     });
 
     // push id
@@ -22,10 +22,27 @@ fn parse_class(classes: &[Class], id: &str) -> String {
 
 /// Converts node to element
 pub fn to_element(node: &Rc<RefCell<Node>>, dom: &Document) -> Result<Element, JsValue> {
-    let this = gesture::bind(node, dom)?;
+    to_element_ns(node, dom, Namespace::Html)
+}
+
+/// Converts node to element, in the namespace of parent if not set
+fn to_element_ns(
+    node: &Rc<RefCell<Node>>,
+    dom: &Document,
+    parent: Namespace,
+) -> Result<Element, JsValue> {
+    let ns = {
+        let attr = &node.borrow().attr;
+        attr.namespace
+            .or_else(|| Namespace::of_tag(&attr.tag))
+            .unwrap_or(parent)
+    };
+
+    let this = gesture::bind(node, dom, ns)?;
     let class = parse_class(&node.borrow().class, &node.borrow().attr.id);
     if !class.is_empty() {
-        this.set_class_name(&class);
+        // `className` is read-only in svg
+        this.set_attribute("class", &class)?;
     }
 
     if !node.borrow().attr.src.is_empty() {
@@ -36,11 +53,39 @@ pub fn to_element(node: &Rc<RefCell<Node>>, dom: &Document) -> Result<Element, J
         this.set_attribute("href", &node.borrow().attr.href)?;
     }
 
+    if !node.borrow().attr.r#type.is_empty() {
+        this.set_attribute("type", &node.borrow().attr.r#type)?;
+    }
+
+    for (k, v) in node.borrow().attr.props.iter() {
+        this.set_attribute(k, v)?;
+    }
+
+    if let Some(drawing) = node.borrow().attr.props.get("data-drawing") {
+        canvas::draw(&this, drawing)?;
+    }
+
+    // Children of `foreignObject` are back to html
+    let inner = if node.borrow().attr.tag == "foreignObject" {
+        Namespace::Html
+    } else {
+        ns
+    };
+
     for child in node.borrow().children.iter() {
         if child.borrow().attr.tag == "plain" {
-            this.set_inner_html(&child.borrow().attr.text);
+            this.append_child(&dom.create_text_node(&child.borrow().attr.text))?;
+        } else if child.borrow().attr.tag == "raw" {
+            // Parse trusted markup in a template, then move its nodes in
+            let template = dom
+                .create_element("template")?
+                .dyn_into::<HtmlTemplateElement>()?;
+            template.set_inner_html(&child.borrow().attr.text);
+            this.append_child(&template.content())?;
+        } else if child.borrow().attr.tag == "portal" {
+            portal::render(child, dom)?;
         } else {
-            this.append_child(&to_element(child, dom)?.into())?;
+            this.append_child(&to_element_ns(child, dom, inner)?.into())?;
         }
     }
 
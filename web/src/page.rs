@@ -1,14 +1,57 @@
-use crate::{node, StyleSheet};
-use elvis_core::Node;
-use std::{cell::RefCell, convert::Into, rc::Rc};
-use wasm_bindgen::prelude::*;
+use crate::{node, portal, Driver, Router, StyleSheet};
+use elvis_core::{Driver as DriverTrait, Node, Subscription, Toasts};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    convert::Into,
+    rc::{Rc, Weak},
+    sync::Arc,
+};
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{
+    Document, Element, HtmlElement, ScrollBehavior, ScrollIntoViewOptions, ScrollToOptions,
+};
 
-/// basic widget without lifecycle nor state
+thread_local! {
+    /// Toasts with timers
+    static TIMERS: RefCell<BTreeSet<usize>> = RefCell::new(BTreeSet::new());
+    /// Mounted pages by their mount selectors
+    static PAGES: RefCell<BTreeMap<String, Page>> = RefCell::new(BTreeMap::new());
+}
+
+/// Subscriptions of component nodes
+type Subscriptions = Vec<(Weak<RefCell<Node>>, Vec<Subscription>)>;
+
+/// basic widget, renders the elements of components again when their state
+/// changes
 #[wasm_bindgen]
 #[derive(Clone, Debug, Default)]
 pub struct Page {
-    tree: Node,
+    tree: Rc<RefCell<Node>>,
     style: Rc<RefCell<StyleSheet>>,
+    subscriptions: Rc<RefCell<Subscriptions>>,
+    mount: String,
+    title: String,
+}
+
+/// Page held by the listeners of components
+struct WeakPage {
+    tree: Weak<RefCell<Node>>,
+    style: Weak<RefCell<StyleSheet>>,
+    subscriptions: Weak<RefCell<Subscriptions>>,
+    mount: String,
+}
+
+impl WeakPage {
+    fn upgrade(&self) -> Option<Page> {
+        Some(Page {
+            tree: self.tree.upgrade()?,
+            style: self.style.upgrade()?,
+            subscriptions: self.subscriptions.upgrade()?,
+            mount: self.mount.clone(),
+            title: "".into(),
+        })
+    }
 }
 
 impl<N> From<N> for Page
@@ -16,38 +59,301 @@ where
     N: Into<Node>,
 {
     fn from(n: N) -> Page {
+        // Navigation widgets render with the current path
+        elvis_core::use_router::<Router>();
         let mut node: Node = n.into();
         node.idx(&mut vec![]);
         Page {
-            tree: node,
+            tree: Rc::new(RefCell::new(node)),
             style: Rc::new(RefCell::new(StyleSheet::default())),
+            subscriptions: Rc::new(RefCell::new(vec![])),
+            mount: "body".into(),
+            title: "".into(),
         }
     }
 }
 
 impl Page {
-    /// Render into body element
+    /// Share one css class between nodes with the same styles
+    pub fn atomic(self, atomic: bool) -> Self {
+        self.style.borrow_mut().atomic = atomic;
+        self
+    }
+
+    /// Set the selector of the element to render into, `body` by default
+    pub fn mount(mut self, selector: &str) -> Self {
+        self.mount = selector.into();
+        self
+    }
+
+    /// Set document title
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Render into mount element, the page lives until another one mounts
+    /// into the same element
     pub fn calling(&mut self) -> Result<(), JsValue> {
+        // set style
+        StyleSheet::shared()?;
+
+        // set title
+        if !self.title.is_empty() {
+            web_sys::window()
+                .unwrap()
+                .document()
+                .unwrap()
+                .set_title(&self.title);
+        }
+
+        self.subscribe(&self.tree.clone());
+        self.render()?;
+        PAGES.with(|p| p.borrow_mut().insert(self.mount.clone(), self.clone()));
+        Ok(())
+    }
+
+    fn downgrade(&self) -> WeakPage {
+        WeakPage {
+            tree: Rc::downgrade(&self.tree),
+            style: Rc::downgrade(&self.style),
+            subscriptions: Rc::downgrade(&self.subscriptions),
+            mount: self.mount.clone(),
+        }
+    }
+
+    /// Render tree into mount element
+    fn render(&self) -> Result<(), JsValue> {
         let window = web_sys::window().unwrap();
         let dom = window.document().unwrap();
 
-        // set style
-        StyleSheet::shared()?;
         self.style()?;
 
-        // set body
-        let body = dom.query_selector("body")?.unwrap();
-        body.set_inner_html("");
-        body.append_child(
-            &node::to_element(&Rc::new(RefCell::new(self.tree.clone())), &dom)?.into(),
-        )?;
+        // set mount
+        let mount = dom.query_selector(&self.mount)?.ok_or_else(|| {
+            JsValue::from_str(&format!("mount element `{}` not found", self.mount))
+        })?;
+        let focused = has_focus(&dom, &mount);
+        mount.set_inner_html("");
+        portal::clear(&dom)?;
+        mount.append_child(&node::to_element(&self.tree, &dom)?.into())?;
+        portal::mounted(&dom)?;
+
+        focus(&mount, focused)?;
+        scroll(&mount)?;
+        toasts(&dom)
+    }
+
+    /// Render components again when their state changes
+    fn subscribe(&self, node: &Rc<RefCell<Node>>) {
+        if let Some(component) = node.borrow().component.clone() {
+            let page = self.downgrade();
+            let target = Rc::downgrade(node);
+            let subscriptions = component.subscribe(Arc::new(move |_| {
+                if let (Some(page), Some(target)) = (page.upgrade(), target.upgrade()) {
+                    if let Err(e) = page.refresh(&target) {
+                        Driver::log(&format!("{:?}", e));
+                    }
+                }
+            }));
+            self.subscriptions
+                .borrow_mut()
+                .push((Rc::downgrade(node), subscriptions));
+        }
+
+        for child in node.borrow().children.iter() {
+            self.subscribe(child);
+        }
+    }
+
+    /// Create component node again, nested components keep their state
+    fn refresh(&self, node: &Rc<RefCell<Node>>) -> Result<(), JsValue> {
+        let component = node.borrow().component.clone();
+        if let Some(component) = component {
+            let old = node.borrow().clone();
+            let stale = portals(node);
+            node.borrow_mut().replace(Node::from_component(component));
+            Node::restore(node, &old);
+            drop(old);
+
+            // drop the subscriptions of the replaced components
+            self.subscriptions
+                .borrow_mut()
+                .retain(|(node, _)| node.strong_count() > 0);
+
+            // components in the new tree
+            for child in node.borrow().children.iter() {
+                self.subscribe(child);
+            }
+
+            self.tree.borrow_mut().idx(&mut vec![]);
+            if !self.patch(node, &stale)? {
+                self.render()?;
+            }
+        }
 
         Ok(())
     }
 
+    /// Replace the element of node only, the elements of its stale portals are
+    /// removed, returns false if the node is not an element under the mount
+    fn patch(&self, node: &Rc<RefCell<Node>>, stale: &[String]) -> Result<bool, JsValue> {
+        if ["plain", "raw", "portal"].contains(&node.borrow().attr.tag.as_str()) {
+            return Ok(false);
+        }
+
+        let dom = web_sys::window().unwrap().document().unwrap();
+        let selector = format!("{} .{}", self.mount, node.borrow().attr.id);
+        let old = match dom.query_selector(&selector)? {
+            Some(old) => old,
+            None => return Ok(false),
+        };
+
+        self.style()?;
+        portal::remove(stale);
+        let focused = has_focus(&dom, &old);
+        let new = node::to_element(node, &dom)?;
+        old.replace_with_with_node_1(&new)?;
+        portal::mounted(&dom)?;
+
+        focus(&new, focused)?;
+        scroll(&new)?;
+        toasts(&dom)?;
+        Ok(true)
+    }
+
     /// Shoud update style
-    fn style(&mut self) -> Result<bool, JsValue> {
-        self.style.borrow_mut().batch(&mut self.tree);
-        Ok(self.style.borrow().ser(self.tree.attr.id.to_string())?)
+    fn style(&self) -> Result<bool, JsValue> {
+        self.style.borrow_mut().batch(&mut self.tree.borrow_mut());
+        self.style.borrow_mut().fonts();
+        self.style.borrow_mut().ser()
+    }
+}
+
+/// Ids of the portals in the tree of node
+fn portals(node: &Rc<RefCell<Node>>) -> Vec<String> {
+    let mut ids = if node.borrow().attr.tag == "portal" {
+        vec![node.borrow().attr.id.clone()]
+    } else {
+        vec![]
+    };
+    for child in node.borrow().children.iter() {
+        ids.append(&mut portals(child));
+    }
+
+    ids
+}
+
+/// If the focused element is in element
+fn has_focus(dom: &Document, el: &Element) -> bool {
+    dom.active_element()
+        .map_or(false, |active| el.contains(Some(&active)))
+}
+
+/// Focus the `data-focus` element of root if the replaced element had focus,
+/// the focus is lost with the replaced element
+fn focus(root: &Element, focused: bool) -> Result<(), JsValue> {
+    if !focused {
+        return Ok(());
+    }
+
+    let target = if root.has_attribute("data-focus") {
+        Some(root.clone())
+    } else {
+        root.query_selector("[data-focus]")?
+    };
+    if let Some(el) = target.and_then(|el| el.dyn_into::<HtmlElement>().ok()) {
+        el.focus()?;
+    }
+
+    Ok(())
+}
+
+/// Restore scroll offsets and run scroll requests of root and its descendants,
+/// elements are scrollable after mounted
+fn scroll(root: &Element) -> Result<(), JsValue> {
+    const SCROLLED: &str =
+        "[data-scroll-top], [data-scroll-left], [data-scroll-to], [data-scroll-into]";
+    let scrolled = root.query_selector_all(SCROLLED)?;
+    let mut elements = if root.matches(SCROLLED)? {
+        vec![root.clone()]
+    } else {
+        vec![]
+    };
+    for i in 0..scrolled.length() {
+        if let Some(el) = scrolled.get(i).and_then(|n| n.dyn_into::<Element>().ok()) {
+            elements.push(el);
+        }
     }
+
+    for el in elements {
+        let num = |k: &str| el.get_attribute(k).and_then(|v| v.parse::<f64>().ok());
+
+        if let Some(top) = num("data-scroll-top") {
+            el.set_scroll_top(top as i32);
+        }
+        if let Some(left) = num("data-scroll-left") {
+            el.set_scroll_left(left as i32);
+        }
+
+        let behavior = if el.has_attribute("data-scroll-smooth") {
+            ScrollBehavior::Smooth
+        } else {
+            ScrollBehavior::Auto
+        };
+        if let Some(to) = el.get_attribute("data-scroll-to") {
+            let mut offsets = to
+                .split(',')
+                .map(|v| v.trim().parse::<f64>().unwrap_or(0.0));
+            let mut opts = ScrollToOptions::new();
+            opts.top(offsets.next().unwrap_or(0.0))
+                .left(offsets.next().unwrap_or(0.0))
+                .behavior(behavior);
+            el.scroll_to_with_scroll_to_options(&opts);
+        }
+        if let Some(key) = el.get_attribute("data-scroll-into") {
+            if let Some(target) = el.query_selector(&portal::selector("data-key", &key))? {
+                let mut opts = ScrollIntoViewOptions::new();
+                opts.behavior(behavior);
+                target.scroll_into_view_with_scroll_into_view_options(&opts);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Dismiss toasts after their timeout, toasts render into the overlay root
+fn toasts(dom: &Document) -> Result<(), JsValue> {
+    let shown = dom.query_selector_all("[data-toast][data-timeout]")?;
+    for i in 0..shown.length() {
+        let el = match shown.get(i).and_then(|n| n.dyn_into::<Element>().ok()) {
+            Some(el) => el,
+            None => continue,
+        };
+        let id = el
+            .get_attribute("data-toast")
+            .and_then(|v| v.parse::<usize>().ok());
+        let ms = el
+            .get_attribute("data-timeout")
+            .and_then(|v| v.parse::<i32>().ok());
+        if let (Some(id), Some(ms)) = (id, ms) {
+            if !TIMERS.with(|t| t.borrow_mut().insert(id)) {
+                continue;
+            }
+
+            let dismiss = Closure::once_into_js(move || {
+                TIMERS.with(|t| t.borrow_mut().remove(&id));
+                Toasts::dismiss(id);
+            });
+            web_sys::window()
+                .unwrap()
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    dismiss.unchecked_ref(),
+                    ms,
+                )?;
+        }
+    }
+
+    Ok(())
 }
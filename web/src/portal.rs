@@ -0,0 +1,220 @@
+//! Portals render their children into an overlay root out of the tree
+use crate::{event::EventListener, node};
+use elvis_core::{Node, Placement, Rect};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Document, Element, HtmlElement, KeyboardEvent};
+
+/// Id of the default overlay root
+const ROOT: &str = "elvis-portal";
+
+/// Gap between anchor and floating element in px
+const GAP: f64 = 8.0;
+
+/// Elements can take focus
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+                         select:not([disabled]), textarea:not([disabled]), \
+                         [tabindex]:not([tabindex=\"-1\"])";
+
+thread_local! {
+    /// Elements rendered by portals, in the overlay root or custom targets
+    static RENDERED: RefCell<Vec<Element>> = RefCell::new(vec![]);
+    /// If the focus trap listener is bound to document
+    static TRAPPED: Cell<bool> = Cell::new(false);
+}
+
+/// Selector of attribute with value, quotes and backslashes are escaped
+pub fn selector(name: &str, value: &str) -> String {
+    format!(
+        "[{}=\"{}\"]",
+        name,
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+/// Get the overlay root, create it in body if not exists
+fn root(dom: &Document) -> Result<Element, JsValue> {
+    if let Some(root) = dom.get_element_by_id(ROOT) {
+        return Ok(root);
+    }
+
+    let root = dom.create_element("div")?;
+    root.set_id(ROOT);
+    root.set_attribute("style", "position: fixed; top: 0; left: 0; z-index: 1000;")?;
+    dom.body()
+        .ok_or_else(|| JsValue::from_str("body not found"))?
+        .append_child(&root)?;
+
+    Ok(root)
+}
+
+/// Render the children of portal into its target, the overlay root by default
+pub fn render(portal: &Rc<RefCell<Node>>, dom: &Document) -> Result<(), JsValue> {
+    let target = match portal.borrow().attr.props.get("target") {
+        Some(selector) => dom
+            .query_selector(selector)?
+            .ok_or_else(|| JsValue::from_str(&format!("portal target `{}` not found", selector)))?,
+        None => root(dom)?,
+    };
+
+    for child in portal.borrow().children.iter() {
+        let el = node::to_element(child, dom)?;
+        el.set_attribute("data-portal", &portal.borrow().attr.id)?;
+        target.append_child(&el)?;
+        RENDERED.with(|r| r.borrow_mut().push(el));
+    }
+
+    Ok(())
+}
+
+/// Remove the elements rendered by portals, the content of targets out of
+/// portals stays
+pub fn clear(dom: &Document) -> Result<(), JsValue> {
+    RENDERED.with(|r| r.borrow_mut().drain(..).for_each(|el| el.remove()));
+    if let Some(root) = dom.get_element_by_id(ROOT) {
+        root.set_inner_html("");
+    }
+
+    Ok(())
+}
+
+/// Remove the elements rendered by portals of ids, before their tree renders
+/// again
+pub fn remove(ids: &[String]) {
+    RENDERED.with(|r| {
+        r.borrow_mut().retain(|el| {
+            let stale = el
+                .get_attribute("data-portal")
+                .map_or(false, |id| ids.contains(&id));
+            if stale {
+                el.remove();
+            }
+
+            !stale
+        })
+    });
+}
+
+/// Place floating elements and trap focus after mounted
+pub fn mounted(dom: &Document) -> Result<(), JsValue> {
+    let floating = dom.query_selector_all("[data-for]")?;
+    for i in 0..floating.length() {
+        if let Some(el) = floating.get(i).and_then(|n| n.dyn_into::<Element>().ok()) {
+            place(dom, &el)?;
+        }
+    }
+
+    let traps = dom.query_selector_all("[data-trap]")?;
+    for i in 0..traps.length() {
+        if let Some(el) = traps.get(i).and_then(|n| n.dyn_into::<HtmlElement>().ok()) {
+            trap(dom, el)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Place element next to its anchor
+fn place(dom: &Document, el: &Element) -> Result<(), JsValue> {
+    let key = el.get_attribute("data-for").unwrap_or_default();
+    let anchor = match dom.query_selector(&selector("data-anchor", &key))? {
+        Some(anchor) => anchor.get_bounding_client_rect(),
+        None => return Ok(()),
+    };
+
+    let window = web_sys::window().unwrap();
+    let viewport = Rect::new(
+        0.0,
+        0.0,
+        window.inner_width()?.as_f64().unwrap_or(0.0),
+        window.inner_height()?.as_f64().unwrap_or(0.0),
+    );
+
+    let size = el.get_bounding_client_rect();
+    let placement = Placement::from(
+        el.get_attribute("data-placement")
+            .unwrap_or_default()
+            .as_str(),
+    );
+    let (side, rect) = placement.place(
+        Rect::new(anchor.x(), anchor.y(), anchor.width(), anchor.height()),
+        (size.width(), size.height()),
+        viewport,
+        GAP,
+    );
+
+    el.set_attribute("data-placement", &side.to_string())?;
+
+    // Keep the other inline styles of element
+    if let Some(el) = el.dyn_ref::<HtmlElement>() {
+        let style = el.style();
+        style.set_property("position", "fixed")?;
+        style.set_property("left", &format!("{}px", rect.x))?;
+        style.set_property("top", &format!("{}px", rect.y))?;
+    }
+
+    Ok(())
+}
+
+/// Focus the first focusable element if the focus is out of trap, bind the
+/// focus trap once
+fn trap(dom: &Document, el: HtmlElement) -> Result<(), JsValue> {
+    let active = dom.active_element().map(Into::<web_sys::Node>::into);
+    if !el.contains(active.as_ref()) {
+        let first = el
+            .query_selector(FOCUSABLE)?
+            .and_then(|f| f.dyn_into::<HtmlElement>().ok());
+        match first {
+            Some(first) => first.focus()?,
+            None => el.focus()?,
+        }
+    }
+
+    if TRAPPED.with(|t| t.replace(true)) {
+        return Ok(());
+    }
+
+    // Keep the focus inside the trap of the focused element
+    let doc = dom.clone();
+    EventListener::new(dom, "keydown", move |e| {
+        let e = match e.dyn_ref::<KeyboardEvent>() {
+            Some(e) if e.key() == "Tab" => e,
+            _ => return,
+        };
+
+        let trap = match doc
+            .active_element()
+            .and_then(|a| a.closest("[data-trap]").ok().flatten())
+        {
+            Some(trap) => trap,
+            None => return,
+        };
+
+        let focusable = match trap.query_selector_all(FOCUSABLE) {
+            Ok(list) if list.length() > 0 => list,
+            _ => return e.prevent_default(),
+        };
+
+        let first = focusable.get(0);
+        let last = focusable.get(focusable.length() - 1);
+        let active = doc.active_element().map(Into::<web_sys::Node>::into);
+        let target = if e.shift_key() && (active == first || active == Some(trap.into())) {
+            last
+        } else if !e.shift_key() && active == last {
+            first
+        } else {
+            return;
+        };
+
+        e.prevent_default();
+        if let Some(target) = target.and_then(|t| t.dyn_into::<HtmlElement>().ok()) {
+            let _ = target.focus();
+        }
+    })
+    .forget();
+
+    Ok(())
+}
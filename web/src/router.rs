@@ -21,4 +21,11 @@ impl RouterTrait for Router {
 
         Ok(())
     }
+
+    /// Current path without `.html`
+    fn path() -> Result<String, CoreError> {
+        let path = window().unwrap().location().pathname();
+        CoreError::check::<_, Error, JsValue>(&path)?;
+        Ok(elvis_core::route(&path.unwrap()))
+    }
 }
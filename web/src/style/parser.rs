@@ -1,12 +1,15 @@
 use elvis_core::Class;
 
 /// Parse class to web string
-pub fn parse_class(class: &Class) -> &'static str {
+pub fn parse_class(class: &Class) -> String {
     match class {
         Class::Center => "center",
         Class::Flex => "flex",
         Class::Col => "col",
         Class::Row => "row",
+        Class::Image => "image",
+        Class::Named(name) => name,
         Class::Empty => "",
     }
+    .to_string()
 }
@@ -1,12 +1,26 @@
-use elvis_core::Node;
-use std::collections::HashMap;
-use wasm_bindgen::prelude::*;
+use elvis_core::{Class, Node, Registry};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    hash::Hasher,
+};
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{CssStyleRule, CssStyleSheet, HtmlStyleElement};
 
 /// style sheet
 #[derive(Clone, Default, Debug)]
 pub struct StyleSheet {
     /// Style table
     pub table: HashMap<String, String>,
+    /// Font face rules
+    pub fonts: Vec<String>,
+    /// Share one class between nodes with the same styles
+    pub atomic: bool,
+    /// Reference counts of rules
+    pub refs: HashMap<String, usize>,
+    /// Rules inserted into document
+    inserted: HashMap<String, String>,
+    /// Font faces inserted into document, they are never dropped
+    inserted_fonts: HashSet<String>,
 }
 
 impl<'s> StyleSheet {
@@ -24,7 +38,19 @@ impl<'s> StyleSheet {
                     "  padding: 0;",
                     "  height: 100%;",
                     "  width: 100%;",
-                    "  overflow: hidden;",
+                    "}",
+                    "[data-status=\"loading\"] > [data-fallback],",
+                    "[data-status=\"loaded\"] > [data-fallback],",
+                    "[data-status=\"loaded\"] > [data-placeholder],",
+                    "[data-status=\"failed\"] > [data-placeholder],",
+                    "[data-status=\"failed\"] > [data-replaced] {",
+                    "  display: none;",
+                    "}",
+                    "[data-status=\"loaded\"] > [data-placeholder] + [data-image] {",
+                    "  position: static;",
+                    "}",
+                    "[data-toast-host] ~ [data-toast-host] {",
+                    "  display: none;",
                     "}",
                 ]
                 .join("\n"),
@@ -39,98 +65,169 @@ impl<'s> StyleSheet {
         Ok(())
     }
 
-    /// Serialize style into html
-    pub fn ser(&self, id: String) -> Result<bool, JsValue> {
-        let mut should_append_class_sheet = false;
-        let mut should_append_widget_sheet = false;
-        let mut should_reset_class_sheet = false;
-        let mut should_reset_widget_sheet = false;
+    /// Get the css sheet of style tag, create it if not exists
+    fn sheet(id: &str) -> Result<CssStyleSheet, JsValue> {
         let window = web_sys::window().unwrap();
         let document = window.document().unwrap();
-        let class_ss = document
-            .query_selector("#elvis-shared")?
-            .unwrap_or_else(|| {
-                should_append_class_sheet = true;
-                let sheet = document.create_element("style").unwrap();
-                sheet.set_id("elvis-shared");
-                sheet
-            });
-
-        let widget_ss = document
-            .query_selector(&format!("#{}", id))?
-            .unwrap_or_else(|| {
-                should_append_widget_sheet = true;
-                let sheet = document.create_element("style").unwrap();
-                sheet.set_id(&id);
-                sheet
-            });
-
-        let class_ss_inner = class_ss.inner_html();
-        let widget_ss_inner = widget_ss.inner_html();
-
-        let mut class = class_ss_inner;
-        let mut widget = widget_ss_inner.clone();
-        self.table.iter().for_each(|(k, v)| {
-            let css_text = format!("\n\n{} {{\n{}\n}}", k, v);
-            if k.starts_with(".elvis") && !widget.contains(&css_text.trim()) {
-                widget.push_str(&css_text);
-                if !should_reset_widget_sheet {
-                    should_reset_widget_sheet = true;
-                }
-            } else if !k.starts_with(".elvis") && k.starts_with('.') && !class.contains(&k[..]) {
-                class.push_str(&css_text);
-                if !should_reset_class_sheet {
-                    should_reset_class_sheet = true;
-                }
+        let tag = match document.query_selector(&format!("#{}", id))? {
+            Some(tag) => tag,
+            None => {
+                let tag = document.create_element("style")?;
+                tag.set_id(id);
+                document
+                    .query_selector("html")?
+                    .unwrap()
+                    .append_child(&tag)?;
+                tag
             }
-        });
+        };
 
-        if should_reset_class_sheet {
-            class_ss.set_inner_html(class.trim());
+        tag.dyn_into::<HtmlStyleElement>()?
+            .sheet()
+            .ok_or_else(|| JsValue::from_str("style sheet not found"))?
+            .dyn_into::<CssStyleSheet>()
+            .map_err(|e| e.into())
+    }
+
+    /// Delete style rule by selector
+    fn delete(sheet: &CssStyleSheet, selector: &str) -> Result<(), JsValue> {
+        let rules = sheet.css_rules()?;
+        for i in (0..rules.length()).rev() {
+            if let Some(rule) = rules.item(i) {
+                if let Some(rule) = rule.dyn_ref::<CssStyleRule>() {
+                    if rule.selector_text() == selector {
+                        sheet.delete_rule(i)?;
+                    }
+                }
+            }
         }
 
-        if widget_ss_inner.ne(&widget) {
-            widget_ss.set_inner_html(widget.trim());
+        Ok(())
+    }
+
+    /// Serialize style into document
+    ///
+    /// Only the changed rules are inserted or deleted, rules no longer
+    /// referenced by any node are dropped.
+    pub fn ser(&mut self) -> Result<bool, JsValue> {
+        let mut changed = false;
+        let class_ss = StyleSheet::sheet("elvis-shared")?;
+        let widget_ss = StyleSheet::sheet("elvis-widgets")?;
+
+        // Drop rules of unmounted nodes
+        let refs = &self.refs;
+        self.table
+            .retain(|k, _| refs.get(k).map_or(false, |r| *r > 0));
+
+        // Delete outdated rules
+        let stale = self
+            .inserted
+            .iter()
+            .filter(|(k, v)| self.table.get(*k) != Some(v))
+            .map(|(k, _)| k.to_string())
+            .collect::<Vec<String>>();
+        for k in stale {
+            if k.starts_with(".elvis") {
+                StyleSheet::delete(&widget_ss, &k)?;
+            } else {
+                StyleSheet::delete(&class_ss, &k)?;
+            }
+
+            self.inserted.remove(&k);
+            changed = true;
         }
 
-        if should_append_class_sheet {
-            document
-                .query_selector("html")?
-                .unwrap()
-                .append_child(&class_ss)?;
+        // Insert new rules
+        for (k, v) in self.table.iter() {
+            if self.inserted.contains_key(k) || !k.starts_with('.') {
+                continue;
+            }
+
+            let css_text = format!("{} {{\n{}\n}}", k, v);
+            if k.starts_with(".elvis") {
+                widget_ss.insert_rule_with_index(&css_text, widget_ss.css_rules()?.length())?;
+            } else {
+                class_ss.insert_rule_with_index(&css_text, class_ss.css_rules()?.length())?;
+            }
+
+            self.inserted.insert(k.to_string(), v.to_string());
+            changed = true;
         }
 
-        if should_append_widget_sheet {
-            document
-                .query_selector("html")?
-                .unwrap()
-                .append_child(&widget_ss)?;
+        // Insert font faces
+        for f in self.fonts.iter() {
+            if self.inserted_fonts.insert(f.to_string()) {
+                class_ss.insert_rule_with_index(f, 0)?;
+                changed = true;
+            }
         }
 
-        Ok(should_reset_class_sheet && should_reset_widget_sheet)
+        Ok(changed)
     }
 
-    /// Batch style from node
+    /// Batch style from node, recount the references of rules
     pub fn batch(&mut self, t: &mut Node) {
+        self.refs.clear();
+        self.walk(t);
+    }
+
+    /// Batch style from node and its children
+    fn walk(&mut self, t: &mut Node) {
         // Generate id-style into table
         if !t.style.is_empty() {
-            self.widget(
-                &t.attr.id,
-                &t.style
-                    .iter()
-                    .map(|s| s.to_css())
-                    .collect::<Vec<String>>()
-                    .join(";"),
-            );
+            let css = t
+                .style
+                .iter()
+                .map(|s| s.to_css())
+                .collect::<Vec<String>>()
+                .join(";");
+
+            if self.atomic {
+                let name = StyleSheet::atom(&css);
+                self.widget(&name, &css);
+                if !t.class.contains(&Class::Named(name.clone())) {
+                    t.class.push(Class::Named(name));
+                }
+            } else {
+                self.widget(&t.attr.id, &css);
+            }
         }
 
         for c in t.class.iter() {
-            self.class(super::parse_class(c));
+            self.class(&super::parse_class(c));
         }
 
         t.children
             .iter()
-            .for_each(|it| self.batch(&mut it.borrow_mut()));
+            .for_each(|it| self.walk(&mut it.borrow_mut()));
+    }
+
+    /// Batch font faces from registry
+    pub fn fonts(&mut self) {
+        for face in Registry::fonts() {
+            let css = face.to_css();
+            if !self.fonts.contains(&css) {
+                self.fonts.push(css);
+            }
+        }
+    }
+
+    /// The shared class name of declarations
+    fn atom(css: &str) -> String {
+        let mut hasher = DefaultHasher::new();
+        hasher.write(css.as_bytes());
+
+        format!("elvis-a{:016x}", hasher.finish())
+    }
+
+    /// Count the rules in this sheet
+    pub fn len(&self) -> usize {
+        self.table.len() + self.fonts.len()
+    }
+
+    /// If this sheet has no rules
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     /// Set style to element with id
@@ -144,7 +241,10 @@ impl<'s> StyleSheet {
             }
         });
 
-        let v = self.table.entry(format!(".{}", ti)).or_default();
+        let key = format!(".{}", ti);
+        *self.refs.entry(key.clone()).or_default() += 1;
+
+        let v = self.table.entry(key).or_default();
         if v != &style {
             *v = style[..(style.len() - 1)].to_string();
         }
@@ -152,7 +252,9 @@ impl<'s> StyleSheet {
 
     /// Set style to element with class
     fn class(&mut self, name: &'s str) {
-        if self.table.contains_key(name) && self.table.get(name) != Some(&"".to_string()) {
+        let key = format!(".{}", name);
+        *self.refs.entry(key.clone()).or_default() += 1;
+        if self.table.get(&key).map_or(false, |v| !v.is_empty()) {
             return;
         }
 
@@ -175,13 +277,52 @@ impl<'s> StyleSheet {
             ]
             .join("\n"),
             "row" => vec!["  flex-direction: row;"].join("\n"),
-            _ => "".to_string(),
+            _ => Registry::styles(name)
+                .unwrap_or_default()
+                .iter()
+                .map(|s| format!("  {};", s.to_css()))
+                .collect::<Vec<String>>()
+                .join("\n"),
         };
 
         if style.is_empty() {
             return;
         }
 
-        self.table.insert(format!(".{}", name), style);
+        self.table.insert(key, style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StyleSheet;
+    use elvis_core::{value::Unit, Node, Style};
+
+    /// A list of rows with the same styles
+    fn list(rows: usize) -> Node {
+        let mut list = Node::default().children(
+            (0..rows)
+                .map(|_| {
+                    Node::default().style(vec![
+                        Style::Height(Unit::Px(48.0)),
+                        Style::Width(Unit::Percent(100.0)),
+                    ])
+                })
+                .collect(),
+        );
+        list.idx(&mut vec![]);
+        list
+    }
+
+    #[test]
+    fn atomic_rows_share_one_rule() {
+        let mut sheet = StyleSheet::default();
+        sheet.batch(&mut list(1000));
+        assert_eq!(sheet.len(), 1000);
+
+        let mut sheet = StyleSheet::default();
+        sheet.atomic = true;
+        sheet.batch(&mut list(1000));
+        assert_eq!(sheet.len(), 1);
     }
 }